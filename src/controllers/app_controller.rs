@@ -16,14 +16,17 @@ pub struct AppController {
 
 impl AppController {
     pub fn new() -> Self {
+        let storage = GameStorage::new();
         let mut configs = HashMap::new();
         for game_type in GameType::all() {
-            configs.insert(game_type, GameConfig::default());
+            // Recuperar la última configuración guardada de cada juego
+            let config = storage.configs.get(&game_type).cloned().unwrap_or_default();
+            configs.insert(game_type, config);
         }
         
         Self {
             state: AppState::GameSelection,
-            storage: GameStorage::new(),
+            storage,
             current_game: None,
             current_result: None,
             game_configs: configs,
@@ -52,14 +55,67 @@ impl AppController {
                 Box::new(crate::games::reading_speed::ReadingSpeedGame::new(config))
             }
             GameType::WordMemory => {
-                Box::new(crate::games::word_memory::WordMemoryGame::new(config))
+                let library = WordListLibrary::load(&GameStorage::word_lists_dir());
+                Box::new(crate::games::word_memory::WordMemoryGame::new(config, library))
             }
             GameType::TextComprehension => {
-                Box::new(crate::games::text_comprehension::TextComprehensionGame::new(config))
+                let library = PassageLibrary::load(&GameStorage::passages_dir(), config.import_passage_words);
+                let history = self.storage.get_recent_passage_ids(usize::MAX);
+                Box::new(crate::games::text_comprehension::TextComprehensionGame::new(
+                    config,
+                    library,
+                    history,
+                    self.storage.books.clone(),
+                ))
             }
             GameType::INumbs => {
                 Box::new(crate::games::inumbs::INumbsGame::new(config))
             }
+            GameType::MajorSystem => {
+                Box::new(crate::games::major_system::MajorSystemGame::new(
+                    self.storage.pegs.clone(),
+                    self.storage.get_slowest_pegs(10),
+                ))
+            }
+            GameType::SchulteTable => {
+                Box::new(crate::games::schulte_table::SchulteTableGame::new(config))
+            }
+            GameType::Tachistoscope => {
+                Box::new(crate::games::tachistoscope::TachistoscopeGame::new())
+            }
+            GameType::Flashcards => {
+                Box::new(crate::games::flashcards::FlashcardsGame::new(
+                    config,
+                    self.storage.cards.clone(),
+                    self.storage.pegs.clone(),
+                ))
+            }
+        };
+        
+        self.current_game = Some(game);
+        self.state = AppState::Playing(game_type);
+    }
+    
+    /// Pruebas de recuerdo diferido programadas, de la más próxima a la más lejana
+    pub fn get_pending_recalls(&self) -> Vec<PendingRecall> {
+        let mut pending = self.storage.pending_recalls.clone();
+        pending.sort_by_key(|p| p.due_at());
+        pending
+    }
+    
+    /// Empieza la prueba diferida de la partida terminada en `studied_at`
+    pub fn start_recall_test(&mut self, studied_at: std::time::SystemTime) {
+        let Some(pending) = self.storage.pending_recalls.iter().find(|p| p.studied_at == studied_at).cloned() else {
+            return;
+        };
+        let game_type = pending.game_type.clone();
+        let config = self.game_configs.get(&game_type).cloned().unwrap_or_default();
+        
+        let game: Box<dyn Game> = match game_type {
+            GameType::WordMemory => Box::new(crate::games::word_memory::WordMemoryGame::delayed_test(config, pending)),
+            GameType::INumbs => Box::new(crate::games::inumbs::INumbsGame::delayed_test(config, pending)),
+            // Solo estos juegos programan pruebas diferidas
+            _ => return,
         };
         
         self.current_game = Some(game);
@@ -71,9 +127,13 @@ impl AppController {
             game.update(ui, ctx);
             
             match game.get_state() {
-                GameState::Playing => {}
+                // Lo que el juego ya guardó no se pierde si se cierra la ventana
+                GameState::Playing => game.save_to_storage(&mut self.storage),
                 GameState::Finished => {
                     if let Some(result) = game.get_result() {
+                        game.save_to_storage(&mut self.storage);
+                        let config = game.get_config().cloned();
+                        self.remember_config(result.game_type.clone(), config);
                         self.storage.save_result(result.clone());
                         self.current_result = Some(result);
                         self.state = AppState::Results;
@@ -81,6 +141,11 @@ impl AppController {
                     }
                 }
                 GameState::Aborted => {
+                    game.save_to_storage(&mut self.storage);
+                    if let AppState::Playing(game_type) = self.state.clone() {
+                        let config = game.get_config().cloned();
+                        self.remember_config(game_type, config);
+                    }
                     self.state = AppState::GameSelection;
                     self.current_game = None;
                 }
@@ -88,6 +153,13 @@ impl AppController {
         }
     }
     
+    /// Guarda la configuración con la que terminó el juego, si la expone
+    fn remember_config(&mut self, game_type: GameType, config: Option<GameConfig>) {
+        if let Some(config) = config {
+            self.set_config(game_type, config);
+        }
+    }
+    
     pub fn needs_repaint(&self) -> bool {
         self.current_game.as_ref().is_some_and(|g| g.needs_repaint())
     }
@@ -108,6 +180,18 @@ impl AppController {
         self.storage.get_stats_for_game(game_type)
     }
     
+    pub fn get_inumbs_position_errors(&self, buckets: usize) -> Vec<PositionStats> {
+        self.storage.get_inumbs_position_errors(buckets)
+    }
+    
+    pub fn get_due_card_count(&self) -> usize {
+        self.storage.due_card_count()
+    }
+    
+    pub fn get_forgetting_curve(&self, game_type: &GameType) -> Vec<RetentionPoint> {
+        self.storage.get_forgetting_curve(game_type)
+    }
+    
     pub fn get_all_results(&self) -> Vec<GameResult> {
         self.storage.get_all_results()
     }
@@ -123,7 +207,6 @@ impl AppController {
         self.game_configs.get(game_type).cloned().unwrap_or_default()
     }
     
-    #[allow(dead_code)]
     pub fn set_config(&mut self, game_type: GameType, config: GameConfig) {
         self.game_configs.insert(game_type.clone(), config.clone());
         self.storage.save_config(game_type, config);
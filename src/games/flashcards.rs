@@ -0,0 +1,324 @@
+use super::*;
+use crate::models::{end_of_today, import_cards, merge_cards, peg_cards, Card, FlashcardReview, Grade, PEG_DECK};
+use egui::{Key, RichText};
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::time::SystemTime;
+
+pub struct FlashcardsGame {
+    config: GameConfig,
+    state: CardState,
+    /// Todas las tarjetas; se guardan en cuanto cambian
+    cards: Vec<Card>,
+    cards_changed: bool,
+    pegs: BTreeMap<u8, String>,
+    /// Índices en `cards` de las tarjetas que quedan por repasar en la sesión
+    queue: Vec<usize>,
+    session_size: usize,
+    reviews: Vec<FlashcardReview>,
+    import_path: String,
+    /// Resultado de la última importación, para mostrarlo
+    import_message: Option<Result<String, String>>,
+    finished: bool,
+    should_go_to_menu: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum CardState {
+    Instructions,
+    Front,
+    Back,
+}
+
+impl FlashcardsGame {
+    pub fn new(mut config: GameConfig, cards: Vec<Card>, pegs: BTreeMap<u8, String>) -> Self {
+        // El mazo recordado puede haberse quedado sin tarjetas
+        if config.flashcard_deck.as_ref().is_some_and(|deck| !cards.iter().any(|c| &c.deck == deck)) {
+            config.flashcard_deck = None;
+        }
+
+        Self {
+            config,
+            state: CardState::Instructions,
+            cards,
+            cards_changed: false,
+            pegs,
+            queue: Vec::new(),
+            session_size: 0,
+            reviews: Vec::new(),
+            import_path: String::new(),
+            import_message: None,
+            finished: false,
+            should_go_to_menu: false,
+        }
+    }
+
+    fn decks(&self) -> Vec<String> {
+        let mut decks: Vec<String> = self.cards.iter().map(|c| c.deck.clone()).collect();
+        decks.sort();
+        decks.dedup();
+        decks
+    }
+
+    fn in_deck(&self, card: &Card) -> bool {
+        self.config.flashcard_deck.as_ref().is_none_or(|deck| &card.deck == deck)
+    }
+
+    /// Tarjetas de la sesión: primero las que vencen hoy, de la más atrasada
+    /// a la más reciente, y después las nuevas hasta el límite elegido
+    fn build_queue(&self) -> Vec<usize> {
+        let end = end_of_today();
+        let mut due: Vec<usize> = (0..self.cards.len())
+            .filter(|&i| self.in_deck(&self.cards[i]) && self.cards[i].is_due(end))
+            .collect();
+        due.sort_by_key(|&i| self.cards[i].due);
+
+        let new = (0..self.cards.len())
+            .filter(|&i| self.in_deck(&self.cards[i]) && self.cards[i].is_new())
+            .take(self.config.new_cards_per_session);
+        due.extend(new);
+        due
+    }
+
+    fn import(&mut self) {
+        let path = self.import_path.trim().to_string();
+        self.import_message = Some(import_cards(Path::new(&path)).map(|cards| {
+            let total = cards.len();
+            let added = merge_cards(&mut self.cards, cards);
+            self.cards_changed |= added > 0;
+            format!("Añadidas {} tarjetas ({} ya existían)", added, total - added)
+        }));
+    }
+
+    fn add_peg_cards(&mut self) {
+        let added = merge_cards(&mut self.cards, peg_cards(&self.pegs));
+        self.cards_changed |= added > 0;
+        self.import_message = Some(Ok(format!("Añadidas {} tarjetas al mazo «{}»", added, PEG_DECK)));
+    }
+
+    fn grade_current(&mut self, grade: Grade) {
+        let index = self.queue.remove(0);
+        let card = &mut self.cards[index];
+        let was_new = card.is_new();
+        card.review(grade, SystemTime::now());
+        self.cards_changed = true;
+        self.reviews.push(FlashcardReview {
+            front: card.front.clone(),
+            deck: card.deck.clone(),
+            grade,
+            interval_days: card.interval_days,
+            was_new,
+        });
+
+        // Una tarjeta fallada vuelve al final de la sesión
+        if grade == Grade::Again {
+            self.queue.push(index);
+        }
+        if self.queue.is_empty() {
+            self.finished = true;
+        } else {
+            self.state = CardState::Front;
+        }
+    }
+
+    fn draw_menu_button(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            if ui.button("< Menú").clicked() {
+                self.should_go_to_menu = true;
+            }
+        });
+        ui.separator();
+        ui.add_space(10.0);
+    }
+
+    fn show_instructions(&mut self, ui: &mut egui::Ui) {
+        ui.heading("Tarjetas de Repaso");
+        ui.separator();
+        ui.add_space(10.0);
+
+        ui.group(|ui| {
+            ui.label("📋 Instrucciones:");
+            ui.label("1. Mira el anverso e intenta recordar el reverso");
+            ui.label("2. Muestra la respuesta (Enter o Espacio)");
+            ui.label("3. Valora tu recuerdo (teclas 1 a 4): cuanto mejor, más tarda en volver");
+            ui.label("4. Las tarjetas falladas se repiten al final de la sesión");
+        });
+
+        ui.add_space(20.0);
+
+        ui.group(|ui| {
+            ui.label("⚙️ Configuración:");
+            ui.add_space(10.0);
+
+            let decks = self.decks();
+            ui.horizontal(|ui| {
+                ui.label("Mazo:");
+                egui::ComboBox::from_id_source("flashcard_deck")
+                    .selected_text(self.config.flashcard_deck.as_deref().unwrap_or("Todos"))
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(&mut self.config.flashcard_deck, None, "Todos");
+                        for deck in decks {
+                            ui.selectable_value(&mut self.config.flashcard_deck, Some(deck.clone()), deck);
+                        }
+                    });
+            });
+
+            ui.add_space(10.0);
+            ui.horizontal(|ui| {
+                ui.label("Tarjetas nuevas por sesión:");
+                ui.add(egui::Slider::new(&mut self.config.new_cards_per_session, 0..=100));
+            });
+
+            ui.add_space(10.0);
+            let end = end_of_today();
+            let due = self.cards.iter().filter(|c| self.in_deck(c) && c.is_due(end)).count();
+            let new = self.cards.iter().filter(|c| self.in_deck(c) && c.is_new()).count();
+            ui.label(format!("Para hoy: {} repasos", due));
+            ui.label(format!(
+                "Nuevas: {} (se estudiarán {})",
+                new,
+                new.min(self.config.new_cards_per_session)
+            ));
+            ui.label(format!("Tarjetas en total: {}", self.cards.len()));
+        });
+
+        ui.add_space(20.0);
+
+        ui.group(|ui| {
+            ui.label("📥 Añadir tarjetas:");
+            ui.add_space(6.0);
+            ui.label("Archivo CSV o TSV (anverso, reverso y, opcionalmente, mazo):");
+            ui.horizontal(|ui| {
+                ui.add(egui::TextEdit::singleline(&mut self.import_path).hint_text("/ruta/a/vocabulario.tsv"));
+                if ui.add_enabled(!self.import_path.trim().is_empty(), egui::Button::new("Importar")).clicked() {
+                    self.import();
+                }
+            });
+            if ui
+                .add_enabled(!self.pegs.is_empty(), egui::Button::new("Crear tarjetas del Sistema Mayor"))
+                .clicked()
+            {
+                self.add_peg_cards();
+            }
+            match &self.import_message {
+                Some(Ok(message)) => {
+                    ui.label(message);
+                }
+                Some(Err(error)) => {
+                    ui.colored_label(egui::Color32::from_rgb(200, 120, 0), format!("No se pudo importar: {}", error));
+                }
+                None => {}
+            }
+        });
+
+        ui.add_space(20.0);
+        let queue = self.build_queue();
+        if queue.is_empty() {
+            ui.label("No hay tarjetas para repasar ahora.");
+        }
+        if ui.add_enabled(!queue.is_empty(), egui::Button::new("Comenzar")).clicked() {
+            self.session_size = queue.len();
+            self.queue = queue;
+            self.reviews.clear();
+            self.state = CardState::Front;
+        }
+    }
+
+    fn show_card(&mut self, ui: &mut egui::Ui) {
+        let Some(&index) = self.queue.first() else {
+            return;
+        };
+        let card = self.cards[index].clone();
+        let done = self.reviews.iter().filter(|r| r.grade != Grade::Again).count();
+
+        ui.vertical_centered(|ui| {
+            ui.label(format!("Tarjeta {} de {} · {}", (done + 1).min(self.session_size), self.session_size, card.deck));
+            if card.is_new() {
+                ui.label(RichText::new("Nueva").small());
+            }
+            ui.add_space(40.0);
+            ui.label(RichText::new(&card.front).size(36.0).strong());
+            ui.add_space(20.0);
+
+            if self.state == CardState::Front {
+                ui.add_space(50.0);
+                let key = ui.input(|i| i.key_pressed(Key::Space));
+                if button_with_enter(ui, "Mostrar respuesta") || key {
+                    self.state = CardState::Back;
+                }
+                return;
+            }
+
+            ui.separator();
+            ui.add_space(20.0);
+            ui.label(RichText::new(&card.back).size(28.0));
+            ui.add_space(30.0);
+
+            let keys = [Key::Num1, Key::Num2, Key::Num3, Key::Num4];
+            let mut chosen = None;
+            ui.horizontal(|ui| {
+                for (grade, key) in Grade::all().into_iter().zip(keys) {
+                    let days = card.next_interval(grade);
+                    let label = format!("{} ({} d)", grade.name(), days);
+                    if ui.button(label).clicked() || ui.input(|i| i.key_pressed(key)) {
+                        chosen = Some(grade);
+                    }
+                }
+            });
+            if let Some(grade) = chosen {
+                self.grade_current(grade);
+            }
+        });
+    }
+}
+
+impl Game for FlashcardsGame {
+    fn update(&mut self, ui: &mut egui::Ui, _ctx: &egui::Context) {
+        if self.should_go_to_menu {
+            return;
+        }
+
+        self.draw_menu_button(ui);
+        match self.state {
+            CardState::Instructions => self.show_instructions(ui),
+            CardState::Front | CardState::Back => self.show_card(ui),
+        }
+    }
+
+    fn get_state(&self) -> GameState {
+        if self.finished {
+            GameState::Finished
+        } else if self.should_go_to_menu {
+            GameState::Aborted
+        } else {
+            GameState::Playing
+        }
+    }
+
+    fn get_result(&self) -> Option<GameResult> {
+        if !self.finished {
+            return None;
+        }
+
+        let remembered = self.reviews.iter().filter(|r| r.grade != Grade::Again).count();
+        Some(GameResult {
+            game_type: crate::GameType::Flashcards,
+            score: remembered as f32 / self.reviews.len().max(1) as f32 * 100.0,
+            details: GameDetails::Flashcards {
+                reviews: self.reviews.clone(),
+            },
+            timestamp: SystemTime::now(),
+        })
+    }
+
+    fn get_config(&self) -> Option<&GameConfig> {
+        Some(&self.config)
+    }
+
+    fn save_to_storage(&mut self, storage: &mut GameStorage) {
+        if self.cards_changed {
+            storage.set_cards(self.cards.clone());
+            self.cards_changed = false;
+        }
+    }
+}
@@ -15,8 +15,11 @@ pub struct INumbsGame {
     finished: bool,
     should_go_to_menu: bool,
     correct_answers: usize,
+    box_results: Vec<BoxResult>,
     overall_start: Option<Instant>,
     focus_input: bool,
+    /// Prueba de recuerdo diferido que se está haciendo, si lo es
+    delayed_test: Option<PendingRecall>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -41,18 +44,35 @@ impl INumbsGame {
             finished: false,
             should_go_to_menu: false,
             correct_answers: 0,
+            box_results: Vec::new(),
             overall_start: None,
             focus_input: false,
+            delayed_test: None,
         }
     }
 
-    fn generate_numbers(count: usize) -> Vec<String> {
+    /// Prueba de recuerdo diferido: pide directamente los números de una
+    /// partida anterior, sin volver a mostrarlos
+    pub fn delayed_test(mut config: GameConfig, pending: PendingRecall) -> Self {
+        config.fill_boxes = true;
+        config.number_format = pending.number_format;
+        config.word_count = pending.stimulus.len();
+        let mut game = Self::new(config);
+        game.numbers = pending.stimulus.clone();
+        game.user_inputs = vec![String::new(); game.total_count];
+        game.overall_start = Some(Instant::now());
+        game.state = INumbsState::Filling;
+        game.focus_input = true;
+        game.delayed_test = Some(pending);
+        game
+    }
+
+    fn generate_numbers(count: usize, format: NumberFormat) -> Vec<String> {
         let mut rng = rand::thread_rng();
         let mut v = Vec::with_capacity(count);
         for _ in 0..count {
-            // Generate pairs (00-99)
-            let n: u8 = rng.gen_range(0..100);
-            v.push(format!("{:02}", n));
+            let n = rng.gen_range(0..format.value_count());
+            v.push(format.format(n));
         }
         v
     }
@@ -68,16 +88,69 @@ impl INumbsGame {
         ui.add_space(10.0);
     }
 
+    /// Pasa a rellenar casilleros o termina cuando ya se mostraron los números
+    fn finish_showing(&mut self) {
+        if self.config.fill_boxes {
+            self.state = INumbsState::Filling;
+            self.focus_input = true;
+        } else {
+            self.calculate_result();
+            self.finished = true;
+        }
+    }
+
+    /// Presentación secuencial: cada número se muestra `display_time` y
+    /// después queda la pantalla en blanco durante la pausa configurada.
+    fn show_sequential(&mut self, ui: &mut egui::Ui, start: Instant) {
+        let elapsed = start.elapsed();
+        let gap = self.config.inter_stimulus_gap;
+        
+        if elapsed >= self.display_time + gap {
+            self.current_index += 1;
+            self.start_time = Some(Instant::now());
+            if self.current_index >= self.total_count {
+                self.finish_showing();
+            }
+            return;
+        }
+        
+        ui.vertical_centered(|ui| {
+            ui.add_space(20.0);
+            ui.heading(format!("Número {} de {}", self.current_index + 1, self.total_count));
+            ui.add_space(60.0);
+            
+            if elapsed < self.display_time {
+                if let Some(num) = self.numbers.get(self.current_index) {
+                    ui.label(RichText::new(num).size(64.0).color(egui::Color32::from_rgb(20,120,200)).strong());
+                }
+            } else {
+                // Pausa entre estímulos: reservar el mismo espacio para que no salte la vista
+                ui.label(RichText::new(" ").size(64.0));
+            }
+            
+            ui.add_space(60.0);
+            ui.label("Memoriza los números en el orden en que aparecen");
+        });
+    }
+
     fn calculate_result(&mut self) {
         let mut correct = 0usize;
+        let mut boxes = Vec::with_capacity(self.numbers.len());
         for (i, expected) in self.numbers.iter().enumerate() {
-            if let Some(ans) = self.user_inputs.get(i) {
-                if ans.trim() == expected.trim() {
-                    correct += 1;
-                }
+            let answer = self.user_inputs.get(i).map(|a| a.trim()).unwrap_or_default();
+            let is_correct = self.config.number_format.matches(expected, answer);
+            if is_correct {
+                correct += 1;
             }
+            boxes.push(BoxResult {
+                expected: expected.clone(),
+                answer: answer.to_string(),
+                correct: is_correct,
+            });
         }
         self.correct_answers = correct;
+        // Sin casilleros no hay respuesta por posición que guardar
+        self.box_results = if self.config.fill_boxes { boxes } else { Vec::new() };
     }
 }
 
@@ -96,6 +169,7 @@ impl Game for INumbsGame {
                     ui.label("📋 Instrucciones:");
                     ui.label("1. Elige la cantidad de números a memorizar");
                     ui.label("2. Elige los segundos de visualización por número");
+                    ui.label("   (en modo Secuencial cada número aparece solo, uno tras otro)");
                     ui.label("3. Activa 'Rellenar casilleros' si quieres completar los números después");
                     ui.label("4. Presiona Comenzar para iniciar el ejercicio");
                 });
@@ -124,6 +198,37 @@ impl Game for INumbsGame {
                         }
                     });
 
+                    ui.add_space(10.0);
+                    ui.horizontal(|ui| {
+                        ui.label("Formato:");
+                        egui::ComboBox::from_id_source("inumbs_format")
+                            .selected_text(self.config.number_format.name())
+                            .show_ui(ui, |ui| {
+                                for format in NumberFormat::all() {
+                                    let name = format.name().to_string();
+                                    ui.selectable_value(&mut self.config.number_format, format, name);
+                                }
+                            });
+                    });
+
+                    ui.add_space(10.0);
+                    ui.horizontal(|ui| {
+                        ui.label("Presentación:");
+                        ui.radio_value(&mut self.config.presentation, PresentationMode::Grid, PresentationMode::Grid.name());
+                        ui.radio_value(&mut self.config.presentation, PresentationMode::Sequential, PresentationMode::Sequential.name());
+                    });
+
+                    if self.config.presentation == PresentationMode::Sequential {
+                        ui.add_space(10.0);
+                        ui.horizontal(|ui| {
+                            ui.label("Pausa entre números (ms):");
+                            let mut gap_ms = self.config.inter_stimulus_gap.as_millis() as u64;
+                            if ui.add(egui::DragValue::new(&mut gap_ms).speed(50).clamp_range(0..=5000)).changed() {
+                                self.config.inter_stimulus_gap = Duration::from_millis(gap_ms);
+                            }
+                        });
+                    }
+
                     ui.add_space(10.0);
                     ui.horizontal(|ui| {
                         ui.label("Rellenar casilleros:");
@@ -136,12 +241,23 @@ impl Game for INumbsGame {
                     ui.add_space(10.0);
                     ui.label(format!("Cantidad: {}", self.total_count));
                     ui.label(format!("Segundos por número: {}s", self.display_time.as_secs()));
+                    ui.label(format!("Formato: {}", self.config.number_format.name()));
+                    ui.label(format!("Presentación: {}", self.config.presentation.name()));
+                    if self.config.presentation == PresentationMode::Sequential {
+                        ui.label(format!("Pausa entre números: {} ms", self.config.inter_stimulus_gap.as_millis()));
+                    }
                     ui.label(format!("Rellenar casilleros: {}", if self.config.fill_boxes { "Sí" } else { "No" }));
+                    
+                    // Sin casilleros no hay respuestas con las que comparar la prueba diferida
+                    if self.config.fill_boxes {
+                        ui.add_space(10.0);
+                        delayed_recall_selector(ui, "inumbs_delay", &mut self.config.delayed_recall);
+                    }
                 });
 
                 ui.add_space(20.0);
                 if ui.button("Comenzar").clicked() {
-                    self.numbers = Self::generate_numbers(self.total_count);
+                    self.numbers = Self::generate_numbers(self.total_count, self.config.number_format);
                     self.current_index = 0;
                     self.start_time = Some(Instant::now());
                     self.overall_start = Some(Instant::now());
@@ -154,16 +270,15 @@ impl Game for INumbsGame {
                 self.draw_menu_button(ui);
 
                 if let Some(start) = self.start_time {
+                    if self.config.presentation == PresentationMode::Sequential {
+                        self.show_sequential(ui, start);
+                        return;
+                    }
+                    
                     let elapsed = start.elapsed();
                     if elapsed >= self.display_time {
                         // finished showing all numbers at once
-                        if self.config.fill_boxes {
-                            self.state = INumbsState::Filling;
-                            self.focus_input = true;
-                        } else {
-                            self.calculate_result();
-                            self.finished = true;
-                        }
+                        self.finish_showing();
                         return;
                     }
 
@@ -215,7 +330,8 @@ impl Game for INumbsGame {
                         for col in 0..cols {
                             let idx = row * cols + col;
                             if idx >= self.total_count { break; }
-                            let text_edit = egui::TextEdit::singleline(&mut self.user_inputs[idx]).desired_width(60.0);
+                            let width = 30.0 + 12.0 * self.config.number_format.width() as f32;
+                            let text_edit = egui::TextEdit::singleline(&mut self.user_inputs[idx]).desired_width(width);
                             let response = ui.add(text_edit);
                             
                             // Autofocus on first input
@@ -249,6 +365,12 @@ impl Game for INumbsGame {
     fn get_result(&self) -> Option<GameResult> {
         if !self.finished { return None; }
 
+        if let Some(pending) = &self.delayed_test {
+            let answers = self.box_results.iter().map(|b| b.answer.clone()).collect();
+            let item_scores = self.box_results.iter().map(|b| if b.correct { 1.0 } else { 0.0 }).collect();
+            return Some(pending.to_result(self.numbers.clone(), answers, item_scores));
+        }
+
         let time_taken = self.overall_start.map(|s| s.elapsed()).unwrap_or(Duration::from_secs(0));
         let score = if self.total_count == 0 { 0.0 } else { (self.correct_answers as f32 / self.total_count as f32) * 100.0 };
 
@@ -259,6 +381,9 @@ impl Game for INumbsGame {
                 correct: self.correct_answers,
                 total: self.total_count,
                 time_taken,
+                presentation: self.config.presentation,
+                boxes: self.box_results.clone(),
+                number_format: self.config.number_format,
             },
             timestamp: std::time::SystemTime::now(),
         })
@@ -267,4 +392,28 @@ impl Game for INumbsGame {
     fn needs_repaint(&self) -> bool {
         matches!(self.state, INumbsState::Showing) && !self.finished && !self.should_go_to_menu
     }
+
+    fn get_config(&self) -> Option<&GameConfig> {
+        // La prueba diferida usa el formato de la partida original, no el elegido
+        self.delayed_test.is_none().then_some(&self.config)
+    }
+
+    fn save_to_storage(&mut self, storage: &mut GameStorage) {
+        if !self.finished {
+            return;
+        }
+        match (&self.delayed_test, self.config.delayed_recall) {
+            (Some(pending), _) => storage.complete_recall(pending.studied_at),
+            (None, Some(delay)) if self.config.fill_boxes => storage.schedule_recall(PendingRecall {
+                game_type: crate::GameType::INumbs,
+                delay,
+                studied_at: std::time::SystemTime::now(),
+                immediate_score: self.correct_answers as f32 / self.total_count.max(1) as f32,
+                stimulus: self.numbers.clone(),
+                recall_mode: RecallMode::default(),
+                number_format: self.config.number_format,
+            }),
+            _ => {}
+        }
+    }
 }
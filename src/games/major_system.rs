@@ -0,0 +1,378 @@
+use super::*;
+use egui::RichText;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::BTreeMap;
+use std::time::Instant;
+use crate::utils::normalize::loosely_equal;
+
+pub struct MajorSystemGame {
+    state: PegState,
+    pegs: BTreeMap<u8, String>,
+    pegs_edited: bool,
+    edit_buffer: Vec<String>,
+    slow_pegs: Vec<u8>,
+    review_slow: bool,
+    drill_mode: DrillMode,
+    total_trials: usize,
+    trials: Vec<(u8, PegDirection)>,
+    current_trial: usize,
+    start_time: Option<Instant>,
+    user_input: String,
+    responses: Vec<PegResponse>,
+    finished: bool,
+    should_go_to_menu: bool,
+    focus_input: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum PegState {
+    Instructions,
+    Editing,
+    Drilling,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum DrillMode {
+    NumberToWord,
+    WordToNumber,
+    Mixed,
+}
+
+impl DrillMode {
+    fn name(&self) -> &str {
+        match self {
+            DrillMode::NumberToWord => "Número → palabra",
+            DrillMode::WordToNumber => "Palabra → número",
+            DrillMode::Mixed => "Mixto",
+        }
+    }
+}
+
+impl MajorSystemGame {
+    pub fn new(pegs: BTreeMap<u8, String>, slow_pegs: Vec<u8>) -> Self {
+        Self {
+            state: PegState::Instructions,
+            pegs,
+            pegs_edited: false,
+            edit_buffer: Vec::new(),
+            slow_pegs,
+            review_slow: false,
+            drill_mode: DrillMode::NumberToWord,
+            total_trials: 20,
+            trials: Vec::new(),
+            current_trial: 0,
+            start_time: None,
+            user_input: String::new(),
+            responses: Vec::new(),
+            finished: false,
+            should_go_to_menu: false,
+            focus_input: false,
+        }
+    }
+
+    fn generate_trials(&self) -> Vec<(u8, PegDirection)> {
+        let mut rng = rand::thread_rng();
+        let mut numbers: Vec<u8> = if self.review_slow && !self.slow_pegs.is_empty() {
+            self.slow_pegs.clone()
+        } else {
+            self.pegs.keys().copied().collect()
+        };
+        // La lista puede haberse editado después de calcular los más lentos
+        numbers.retain(|n| self.pegs.contains_key(n));
+        numbers.shuffle(&mut rng);
+        numbers.truncate(self.total_trials);
+
+        numbers
+            .into_iter()
+            .map(|n| {
+                let direction = match self.drill_mode {
+                    DrillMode::NumberToWord => PegDirection::NumberToWord,
+                    DrillMode::WordToNumber => PegDirection::WordToNumber,
+                    DrillMode::Mixed => {
+                        if rng.gen_bool(0.5) {
+                            PegDirection::NumberToWord
+                        } else {
+                            PegDirection::WordToNumber
+                        }
+                    }
+                };
+                (n, direction)
+            })
+            .collect()
+    }
+
+    fn is_correct(&self, number: u8, direction: PegDirection, answer: &str) -> bool {
+        let answer = answer.trim();
+        match direction {
+            PegDirection::NumberToWord => self
+                .pegs
+                .get(&number)
+                .is_some_and(|peg| loosely_equal(peg, answer)),
+            PegDirection::WordToNumber => {
+                // Si varias casillas comparten palabra, cualquiera de ellas vale
+                let Some(peg) = self.pegs.get(&number) else {
+                    return false;
+                };
+                self.pegs
+                    .iter()
+                    .any(|(n, p)| loosely_equal(p, peg) && format!("{:02}", n) == answer)
+            }
+        }
+    }
+
+    fn submit_answer(&mut self) {
+        let Some(&(number, direction)) = self.trials.get(self.current_trial) else {
+            return;
+        };
+        let response_time = self.start_time.map(|s| s.elapsed()).unwrap_or_default();
+        let correct = self.is_correct(number, direction, &self.user_input);
+
+        self.responses.push(PegResponse {
+            number,
+            direction,
+            answer: self.user_input.trim().to_string(),
+            correct,
+            response_time,
+        });
+        self.user_input.clear();
+
+        self.current_trial += 1;
+        if self.current_trial >= self.trials.len() {
+            self.finished = true;
+        } else {
+            self.start_time = Some(Instant::now());
+            self.focus_input = true;
+        }
+    }
+
+    fn start_editing(&mut self) {
+        self.edit_buffer = (0..100u8)
+            .map(|n| self.pegs.get(&n).cloned().unwrap_or_default())
+            .collect();
+        self.state = PegState::Editing;
+    }
+
+    fn save_edits(&mut self) {
+        self.pegs = self
+            .edit_buffer
+            .iter()
+            .enumerate()
+            .filter(|(_, word)| !word.trim().is_empty())
+            .map(|(n, word)| (n as u8, word.trim().to_string()))
+            .collect();
+        self.pegs_edited = true;
+        self.state = PegState::Instructions;
+    }
+
+    fn draw_menu_button(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            if ui.button("< Menú").clicked() {
+                self.should_go_to_menu = true;
+                self.state = PegState::Instructions;
+            }
+        });
+        ui.separator();
+        ui.add_space(10.0);
+    }
+}
+
+impl Game for MajorSystemGame {
+    fn update(&mut self, ui: &mut egui::Ui, _ctx: &egui::Context) {
+        if self.should_go_to_menu { return; }
+
+        match self.state {
+            PegState::Instructions => {
+                self.draw_menu_button(ui);
+                ui.heading("Sistema Mayor");
+                ui.separator();
+                ui.add_space(10.0);
+
+                ui.group(|ui| {
+                    ui.label("📋 Instrucciones:");
+                    ui.label("1. Define tu lista de imágenes (pegs) del 00 al 99 con 'Editar lista'");
+                    ui.label("2. Elige el sentido: ver el número y escribir tu palabra, o al revés");
+                    ui.label("3. Responde lo más rápido posible: se mide el tiempo de cada respuesta");
+                    ui.label("4. Usa 'Repasar los más lentos' para practicar las imágenes que más te cuestan");
+                });
+
+                ui.add_space(20.0);
+
+                ui.group(|ui| {
+                    ui.label("⚙️ Configuración:");
+                    ui.add_space(10.0);
+
+                    ui.horizontal(|ui| {
+                        ui.label("Sentido:");
+                        for mode in [DrillMode::NumberToWord, DrillMode::WordToNumber, DrillMode::Mixed] {
+                            let name = mode.name().to_string();
+                            ui.radio_value(&mut self.drill_mode, mode, name);
+                        }
+                    });
+
+                    ui.add_space(10.0);
+                    ui.horizontal(|ui| {
+                        ui.label("Preguntas:");
+                        ui.radio_value(&mut self.total_trials, 10, "10");
+                        ui.radio_value(&mut self.total_trials, 20, "20");
+                        ui.radio_value(&mut self.total_trials, 50, "50");
+                        ui.radio_value(&mut self.total_trials, 100, "100");
+                    });
+
+                    ui.add_space(10.0);
+                    ui.add_enabled_ui(!self.slow_pegs.is_empty(), |ui| {
+                        ui.checkbox(&mut self.review_slow, "Repasar los más lentos");
+                    });
+                    if !self.slow_pegs.is_empty() {
+                        let slow: Vec<String> = self.slow_pegs.iter().map(|n| format!("{:02}", n)).collect();
+                        ui.label(format!("Más lentos: {}", slow.join(", ")));
+                    }
+
+                    ui.add_space(10.0);
+                    ui.label(format!("Imágenes definidas: {} / 100", self.pegs.len()));
+                });
+
+                ui.add_space(20.0);
+                ui.horizontal(|ui| {
+                    if ui.button("✏️ Editar lista").clicked() {
+                        self.start_editing();
+                    }
+
+                    let can_start = !self.pegs.is_empty();
+                    if ui.add_enabled(can_start, egui::Button::new("Comenzar")).clicked() {
+                        self.trials = self.generate_trials();
+                        self.current_trial = 0;
+                        self.responses.clear();
+                        self.user_input.clear();
+                        self.start_time = Some(Instant::now());
+                        self.focus_input = true;
+                        self.state = PegState::Drilling;
+                    }
+                });
+
+                if self.pegs.is_empty() {
+                    ui.add_space(10.0);
+                    ui.label("Define al menos una imagen para poder practicar.");
+                }
+            }
+
+            PegState::Editing => {
+                ui.horizontal(|ui| {
+                    if ui.button("Guardar").clicked() {
+                        self.save_edits();
+                    }
+                    if ui.button("Cancelar").clicked() {
+                        self.state = PegState::Instructions;
+                    }
+                });
+                ui.separator();
+                ui.heading("Lista de imágenes (00-99)");
+                ui.add_space(10.0);
+
+                egui::ScrollArea::vertical().show(ui, |ui| {
+                    egui::Grid::new("peg_editor")
+                        .spacing([8.0, 4.0])
+                        .show(ui, |ui| {
+                            for (n, word) in self.edit_buffer.iter_mut().enumerate() {
+                                ui.monospace(format!("{:02}", n));
+                                ui.add(egui::TextEdit::singleline(word).desired_width(110.0));
+                                if n % 4 == 3 {
+                                    ui.end_row();
+                                }
+                            }
+                        });
+                });
+            }
+
+            PegState::Drilling => {
+                self.draw_menu_button(ui);
+
+                let Some(&(number, direction)) = self.trials.get(self.current_trial) else {
+                    return;
+                };
+
+                ui.vertical_centered(|ui| {
+                    ui.heading(format!("Pregunta {} de {}", self.current_trial + 1, self.trials.len()));
+                    ui.add_space(30.0);
+
+                    let (prompt, hint) = match direction {
+                        PegDirection::NumberToWord => (format!("{:02}", number), "Escribe tu imagen para este número"),
+                        PegDirection::WordToNumber => (
+                            self.pegs.get(&number).cloned().unwrap_or_default(),
+                            "Escribe el número (00-99) de esta imagen",
+                        ),
+                    };
+                    ui.label(RichText::new(prompt).size(48.0).color(egui::Color32::from_rgb(50, 50, 200)).strong());
+                    ui.add_space(10.0);
+                    ui.label(hint);
+                    ui.add_space(20.0);
+
+                    let text_edit = egui::TextEdit::singleline(&mut self.user_input)
+                        .desired_width(200.0)
+                        .font(egui::TextStyle::Heading);
+                    let response = ui.add(text_edit);
+                    if self.focus_input {
+                        response.request_focus();
+                        self.focus_input = false;
+                    }
+
+                    if let Some(last) = self.responses.last() {
+                        ui.add_space(20.0);
+                        let expected = match last.direction {
+                            PegDirection::NumberToWord => self.pegs.get(&last.number).cloned().unwrap_or_default(),
+                            PegDirection::WordToNumber => format!("{:02}", last.number),
+                        };
+                        ui.label(format!(
+                            "Anterior: {} ({:.1} s){}",
+                            if last.correct { "✅" } else { "❌" },
+                            last.response_time.as_secs_f32(),
+                            if last.correct { String::new() } else { format!(" — era «{}»", expected) }
+                        ));
+                    }
+                });
+
+                ui.add_space(20.0);
+                if button_with_enter(ui, "Responder") {
+                    self.submit_answer();
+                }
+            }
+        }
+    }
+
+    fn get_state(&self) -> GameState {
+        if self.finished {
+            GameState::Finished
+        } else if self.should_go_to_menu {
+            GameState::Aborted
+        } else {
+            GameState::Playing
+        }
+    }
+
+    fn get_result(&self) -> Option<GameResult> {
+        if !self.finished { return None; }
+
+        let correct = self.responses.iter().filter(|r| r.correct).count();
+        let score = if self.responses.is_empty() {
+            0.0
+        } else {
+            correct as f32 / self.responses.len() as f32 * 100.0
+        };
+
+        Some(GameResult {
+            game_type: crate::GameType::MajorSystem,
+            score,
+            details: GameDetails::MajorSystem {
+                responses: self.responses.clone(),
+            },
+            timestamp: std::time::SystemTime::now(),
+        })
+    }
+
+    fn save_to_storage(&mut self, storage: &mut GameStorage) {
+        if self.pegs_edited {
+            storage.set_pegs(self.pegs.clone());
+            self.pegs_edited = false;
+        }
+    }
+}
@@ -2,9 +2,15 @@ pub mod reading_speed;
 pub mod word_memory;
 pub mod text_comprehension;
 pub mod inumbs;
+pub mod major_system;
+pub mod rsvp;
+pub mod pacer;
+pub mod schulte_table;
+pub mod tachistoscope;
+pub mod flashcards;
 
 // Re-exportar desde models
-pub use crate::models::{Difficulty, GameConfig, GameState, GameResult, GameDetails};
+pub use crate::models::{Difficulty, GameConfig, GameState, GameResult, GameDetails, NumberRound, DigitProgression, RecallOrder, ChunkGrouping, PresentationMode, BoxResult, NumberFormat, GameStorage, PegDirection, PegResponse, ReadingMode, SchulteSymbols, FlashStimulus, FlashTrial, RecallMode, NearMiss, PairTrial, RecallDelay, PendingRecall};
 
 /// Trait común para todos los juegos
 pub trait Game {
@@ -12,10 +18,36 @@ pub trait Game {
     fn get_state(&self) -> GameState;
     fn get_result(&self) -> Option<GameResult>;
     fn needs_repaint(&self) -> bool { false }
+    /// Configuración elegida por el usuario, para recordarla en la próxima partida
+    fn get_config(&self) -> Option<&GameConfig> { None }
+    /// Guarda datos propios del juego (no resultados). Se llama en cada frame
+    /// y al salir del juego, así que solo debe escribir lo que haya cambiado.
+    fn save_to_storage(&mut self, _storage: &mut GameStorage) {}
 }
 
 /// Botón que también responde a la tecla Enter
 pub fn button_with_enter(ui: &mut egui::Ui, text: &str) -> bool {
     let enter_pressed = ui.input(|i| i.key_pressed(egui::Key::Enter));
     ui.button(text).clicked() || enter_pressed
+}
+
+/// Selector de la prueba de recuerdo diferido que se programa al terminar
+pub fn delayed_recall_selector(ui: &mut egui::Ui, id: &str, delay: &mut Option<RecallDelay>) {
+    ui.horizontal(|ui| {
+        ui.label("Recuerdo diferido:");
+        egui::ComboBox::from_id_source(id)
+            .selected_text(delay.as_ref().map_or("No", |d| d.name()))
+            .show_ui(ui, |ui| {
+                ui.selectable_value(delay, None, "No");
+                for option in RecallDelay::all() {
+                    ui.selectable_value(delay, Some(option), option.name());
+                }
+            });
+    });
+    if let Some(d) = delay {
+        ui.label(egui::RichText::new(format!(
+            "Dentro de {} aparecerá en el menú una prueba para recordar lo mismo sin volver a verlo.",
+            d.name()
+        )).small());
+    }
 }
\ No newline at end of file
@@ -0,0 +1,142 @@
+//! Lectura guiada con marcador (meta-guía)
+//!
+//! Reparte el texto en líneas y desplaza un subrayado por cada una al ritmo
+//! objetivo, que puede ir aumentando durante la sesión para forzar la velocidad.
+
+use egui::{Color32, FontId, Key, Stroke};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+pub struct PacerReader {
+    text: String,
+    start_wpm: u32,
+    /// Aumento de la velocidad por cada minuto de lectura
+    ramp_per_minute: u32,
+    line: usize,
+    line_progress: f32,
+    active_time: Duration,
+    last_frame: Option<Instant>,
+    paused: bool,
+    finished: bool,
+    scroll_to_line: bool,
+}
+
+impl PacerReader {
+    pub fn new(text: &str, start_wpm: u32, ramp_per_minute: u32) -> Self {
+        Self {
+            text: text.to_string(),
+            start_wpm: start_wpm.max(1),
+            ramp_per_minute,
+            line: 0,
+            line_progress: 0.0,
+            active_time: Duration::ZERO,
+            last_frame: None,
+            paused: false,
+            finished: false,
+            scroll_to_line: true,
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Velocidad actual del marcador
+    pub fn current_wpm(&self) -> f32 {
+        self.start_wpm as f32 + self.ramp_per_minute as f32 * self.active_time.as_secs_f32() / 60.0
+    }
+
+    fn words_in_row(row: &egui::epaint::text::Row) -> usize {
+        let text: String = row.glyphs.iter().map(|g| g.chr).collect();
+        text.split_whitespace().count()
+    }
+
+    /// Avanza el marcador según el tiempo transcurrido desde el último frame
+    fn advance(&mut self, galley: &egui::Galley) {
+        let now = Instant::now();
+        let dt = self.last_frame.map(|t| now - t).unwrap_or_default();
+        self.last_frame = Some(now);
+        if self.paused || self.finished {
+            return;
+        }
+        self.active_time += dt;
+
+        let mut remaining = dt.as_secs_f32();
+        while remaining > 0.0 {
+            let Some(row) = galley.rows.get(self.line) else {
+                self.finished = true;
+                return;
+            };
+            let words = Self::words_in_row(row);
+            if words == 0 {
+                self.next_line();
+                continue;
+            }
+
+            let line_secs = words as f32 * 60.0 / self.current_wpm();
+            let left = (1.0 - self.line_progress) * line_secs;
+            if remaining < left {
+                self.line_progress += remaining / line_secs;
+                remaining = 0.0;
+            } else {
+                remaining -= left;
+                self.next_line();
+            }
+        }
+    }
+
+    fn next_line(&mut self) {
+        self.line += 1;
+        self.line_progress = 0.0;
+        self.scroll_to_line = true;
+    }
+
+    pub fn update(&mut self, ui: &mut egui::Ui) {
+        if ui.input(|i| i.key_pressed(Key::Space)) {
+            self.paused = !self.paused;
+        }
+
+        ui.horizontal(|ui| {
+            let label = if self.paused { "▶ Continuar" } else { "⏸ Pausa" };
+            if ui.button(label).clicked() {
+                self.paused = !self.paused;
+            }
+            ui.label(format!("Marcador: {:.0} ppm", self.current_wpm()));
+        });
+        ui.label(egui::RichText::new("Sigue el subrayado con la vista · Espacio: pausa").small());
+        ui.add_space(10.0);
+
+        let font = FontId::proportional(18.0);
+        let text_color = ui.visuals().text_color();
+        let wrap_width = ui.available_width().min(700.0);
+        let galley: Arc<egui::Galley> = ui.fonts(|f| f.layout(self.text.clone(), font, text_color, wrap_width));
+
+        self.advance(&galley);
+
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            let (rect, _) = ui.allocate_exact_size(galley.size(), egui::Sense::hover());
+            let painter = ui.painter_at(rect.expand(4.0));
+
+            if let Some(row) = galley.rows.get(self.line) {
+                let row_rect = row.rect.translate(rect.min.to_vec2());
+                let x = row_rect.left() + row_rect.width() * self.line_progress;
+                painter.rect_filled(
+                    egui::Rect::from_min_max(row_rect.left_top(), egui::pos2(x, row_rect.bottom())),
+                    2.0,
+                    Color32::from_rgba_unmultiplied(255, 220, 80, 60),
+                );
+                painter.line_segment(
+                    [egui::pos2(row_rect.left(), row_rect.bottom()), egui::pos2(x, row_rect.bottom())],
+                    Stroke::new(3.0, Color32::from_rgb(230, 150, 0)),
+                );
+
+                if self.scroll_to_line {
+                    ui.scroll_to_rect(row_rect, Some(egui::Align::Center));
+                    self.scroll_to_line = false;
+                }
+            }
+
+            painter.galley(rect.min, galley.clone());
+        });
+    }
+}
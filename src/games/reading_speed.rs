@@ -1,4 +1,5 @@
 use super::*;
+use crate::utils::edit_distance::{self, EditErrors};
 use egui::RichText;
 use rand::Rng;
 use std::time::{Duration, Instant};
@@ -6,8 +7,12 @@ use std::time::{Duration, Instant};
 pub struct ReadingSpeedGame {
     config: GameConfig,
     state: NumberState,
-    training_mode: bool,
+    progression: DigitProgression,
+    staircase: Staircase,
+    order: RecallOrder,
+    chunking: ChunkGrouping,
     start_time: Option<Instant>,
+    overall_start: Option<Instant>,
     number_to_remember: String,
     user_input: String,
     display_time: Duration,
@@ -15,19 +20,78 @@ pub struct ReadingSpeedGame {
     current_round: usize,
     total_rounds: usize,
     correct_answers: usize,
-    round_results: Vec<RoundResult>,
+    round_results: Vec<NumberRound>,
     finished: bool,
     should_go_to_menu: bool,
     base_digit_count: usize,
     focus_input: bool,
 }
 
+/// Escalera adaptativa 2-arriba/1-abajo: dos aciertos seguidos suman un dígito
+/// y cada fallo resta uno. Converge hacia la amplitud con ~70% de aciertos.
 #[derive(Debug, Clone)]
-struct RoundResult {
-    number: String,
-    user_answer: String,
-    correct: bool,
-    accuracy: f32,
+struct Staircase {
+    level: usize,
+    streak: usize,
+    last_step_up: Option<bool>,
+    reversals: Vec<usize>,
+    best_correct: usize,
+}
+
+impl Staircase {
+    const MAX_LEVEL: usize = 30;
+    
+    fn new(start: usize) -> Self {
+        Self {
+            level: start.max(1),
+            streak: 0,
+            last_step_up: None,
+            reversals: Vec::new(),
+            best_correct: 0,
+        }
+    }
+    
+    fn record(&mut self, correct: bool) {
+        if correct {
+            self.best_correct = self.best_correct.max(self.level);
+            self.streak += 1;
+            if self.streak >= 2 {
+                self.streak = 0;
+                self.step(true);
+            }
+        } else {
+            self.streak = 0;
+            self.step(false);
+        }
+    }
+    
+    fn step(&mut self, up: bool) {
+        if self.last_step_up.is_some_and(|last| last != up) {
+            self.reversals.push(self.level);
+        }
+        self.last_step_up = Some(up);
+        self.level = if up {
+            (self.level + 1).min(Self::MAX_LEVEL)
+        } else {
+            self.level.saturating_sub(1).max(1)
+        };
+    }
+    
+    /// Media de los puntos de inversión, descartando los dos primeros cuando hay
+    /// suficientes. Sin inversiones se usa la mayor cantidad acertada.
+    fn estimate(&self) -> f32 {
+        let reversals: &[usize] = if self.reversals.len() >= 4 {
+            &self.reversals[2..]
+        } else {
+            &self.reversals
+        };
+        
+        if reversals.is_empty() {
+            self.best_correct as f32
+        } else {
+            reversals.iter().sum::<usize>() as f32 / reversals.len() as f32
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -44,8 +108,12 @@ impl ReadingSpeedGame {
         Self {
             config,
             state: NumberState::Instructions,
-            training_mode: false,
+            progression: DigitProgression::Random,
+            staircase: Staircase::new(digit_count),
+            order: RecallOrder::Forward,
+            chunking: ChunkGrouping::None,
             start_time: None,
+            overall_start: None,
             number_to_remember: number,
             user_input: String::new(),
             display_time,
@@ -99,63 +167,74 @@ impl ReadingSpeedGame {
     }
 
     fn calculate_digit_count_for_round(&self, round: usize) -> usize {
-        if !self.training_mode {
-            // Modo normal: usar el rango según dificultad
-            match self.config.difficulty {
-                Difficulty::Easy => rand::thread_rng().gen_range(1..=6),
-                Difficulty::Medium => rand::thread_rng().gen_range(7..=10),
-                Difficulty::Hard => rand::thread_rng().gen_range(11..=20),
+        match self.progression {
+            DigitProgression::Random => {
+                // Modo normal: usar el rango según dificultad
+                match self.config.difficulty {
+                    Difficulty::Easy => rand::thread_rng().gen_range(1..=6),
+                    Difficulty::Medium => rand::thread_rng().gen_range(7..=10),
+                    Difficulty::Hard => rand::thread_rng().gen_range(11..=20),
+                }
             }
-        } else {
-            // Modo training: progresión gradual
-            let base_count = self.base_digit_count;
-            
-            match self.config.difficulty {
-                Difficulty::Easy => {
-                    // Aumenta 1 dígito cada ronda
-                    base_count + (round - 1)
-                },
-                Difficulty::Medium => {
-                    // Aumenta 1 dígito cada 2 rondas
-                    base_count + ((round - 1) / 2)
-                },
-                Difficulty::Hard => {
-                    // Aumenta 1 dígito cada 3 rondas (comenzando desde la ronda 10)
-                    if round >= 10 {
-                        base_count + ((round - 10) / 3)
-                    } else {
-                        base_count
-                    }
-                },
+            DigitProgression::Training => {
+                // Modo training: progresión gradual
+                let base_count = self.base_digit_count;
+                
+                match self.config.difficulty {
+                    Difficulty::Easy => {
+                        // Aumenta 1 dígito cada ronda
+                        base_count + (round - 1)
+                    },
+                    Difficulty::Medium => {
+                        // Aumenta 1 dígito cada 2 rondas
+                        base_count + ((round - 1) / 2)
+                    },
+                    Difficulty::Hard => {
+                        // Aumenta 1 dígito cada 3 rondas (comenzando desde la ronda 10)
+                        if round >= 10 {
+                            base_count + ((round - 10) / 3)
+                        } else {
+                            base_count
+                        }
+                    },
+                }
+            }
+            DigitProgression::Adaptive => {
+                // Modo adaptativo: la escalera decide según los aciertos
+                self.staircase.level
             }
         }
     }
 
-    fn calculate_round_accuracy(&self) -> f32 {
-        let original = self.number_to_remember.trim();
-        let user = self.user_input.trim();
+    /// Respuesta que se espera según el orden de recuerdo
+    fn expected_answer(&self) -> String {
+        match self.order {
+            RecallOrder::Forward => self.number_to_remember.trim().to_string(),
+            RecallOrder::Backward => self.number_to_remember.trim().chars().rev().collect(),
+        }
+    }
+
+    /// Respuesta del usuario sin espacios (puede escribirla agrupada)
+    fn answer(&self) -> String {
+        self.user_input.chars().filter(|c| !c.is_whitespace()).collect()
+    }
+
+    /// Alinea la respuesta con la secuencia esperada para puntuar con crédito parcial.
+    /// Un dígito omitido o un par intercambiado solo penaliza ese error,
+    /// no todas las posiciones que vienen detrás.
+    fn calculate_round_accuracy(&self) -> (f32, EditErrors) {
+        let original: Vec<char> = self.expected_answer().chars().collect();
+        let user: Vec<char> = self.answer().chars().collect();
         
         if original.is_empty() {
-            return 0.0;
+            return (0.0, EditErrors::default());
         }
         
-        if original == user {
-            100.0
-        } else {
-            let original_chars: Vec<char> = original.chars().collect();
-            let user_chars: Vec<char> = user.chars().collect();
-            
-            let mut correct = 0;
-            let max_len = original_chars.len().max(user_chars.len());
-            
-            for i in 0..original_chars.len().min(user_chars.len()) {
-                if original_chars[i] == user_chars[i] {
-                    correct += 1;
-                }
-            }
-            
-            if max_len == 0 { 0.0 } else { (correct as f32 / max_len as f32) * 100.0 }
-        }
+        let errors = edit_distance::align(&original, &user);
+        let max_len = original.len().max(user.len());
+        let accuracy = (1.0 - errors.total() as f32 / max_len as f32).max(0.0) * 100.0;
+        
+        (accuracy, errors)
     }
 
     fn calculate_overall_accuracy(&self) -> f32 {
@@ -167,19 +246,39 @@ impl ReadingSpeedGame {
         total_accuracy / self.round_results.len() as f32
     }
 
+    fn total_errors(&self) -> EditErrors {
+        let mut total = EditErrors::default();
+        for round in &self.round_results {
+            total += round.errors;
+        }
+        total
+    }
+
     fn next_round(&mut self) {
-        let accuracy = self.calculate_round_accuracy();
-        let is_correct = self.number_to_remember.trim() == self.user_input.trim();
+        let (accuracy, errors) = self.calculate_round_accuracy();
+        let is_correct = self.expected_answer() == self.answer();
         
         if is_correct {
             self.correct_answers += 1;
         }
         
-        self.round_results.push(RoundResult {
+        if self.progression == DigitProgression::Adaptive {
+            self.staircase.record(is_correct);
+        }
+        
+        let response_time = self.start_time
+            .map(|s| s.elapsed())
+            .unwrap_or_default();
+        
+        self.round_results.push(NumberRound {
             number: self.number_to_remember.clone(),
-            user_answer: self.user_input.clone(),
+            user_answer: self.answer(),
+            digit_count: self.digit_count,
+            display_time: self.display_time,
+            response_time,
             correct: is_correct,
             accuracy,
+            errors,
         });
         
         self.user_input.clear();
@@ -231,6 +330,7 @@ impl Game for ReadingSpeedGame {
                     ui.label("1. Configura el tiempo de visualización, dificultad y número de rondas");
                     ui.label("2. En cada ronda aparecerá un número durante el tiempo configurado");
                     ui.label("3. Después tendrás que escribir el número de memoria");
+                    ui.label("   (en orden inverso si eliges el modo Inverso: 1234 → 4321)");
                     ui.label("4. Completa todas las rondas para obtener tu puntuación final");
                 });
                 
@@ -259,8 +359,27 @@ impl Game for ReadingSpeedGame {
                     
                     ui.add_space(10.0);
                     ui.horizontal(|ui| {
-                        ui.label("Training:");
-                        ui.checkbox(&mut self.training_mode, "");
+                        ui.label("Orden de recuerdo:");
+                        ui.radio_value(&mut self.order, RecallOrder::Forward, RecallOrder::Forward.name());
+                        ui.radio_value(&mut self.order, RecallOrder::Backward, RecallOrder::Backward.name());
+                    });
+                    
+                    ui.add_space(10.0);
+                    ui.horizontal(|ui| {
+                        ui.label("Agrupación:");
+                        for chunking in ChunkGrouping::all() {
+                            let name = chunking.name().to_string();
+                            ui.radio_value(&mut self.chunking, chunking, name);
+                        }
+                    });
+                    
+                    ui.add_space(10.0);
+                    ui.horizontal(|ui| {
+                        ui.label("Progresión:");
+                        for progression in [DigitProgression::Random, DigitProgression::Training, DigitProgression::Adaptive] {
+                            let name = progression.name().to_string();
+                            ui.radio_value(&mut self.progression, progression, name);
+                        }
                     });
                     
                     ui.add_space(10.0);
@@ -289,7 +408,13 @@ impl Game for ReadingSpeedGame {
                     ui.add_space(10.0);
                     ui.label(format!("Tiempo de visualización: {} ms", self.config.duration.as_millis()));
                     ui.label(format!("Número de rondas: {}", self.total_rounds));
-                    ui.label(format!("Training: {}", if self.training_mode { "Activado" } else { "Desactivado" }));
+                    ui.label(format!("Orden de recuerdo: {}", self.order.name()));
+                    ui.label(format!("Agrupación: {}", self.chunking.name()));
+                    ui.label(format!("Progresión: {}", self.progression.name()));
+                    if self.progression == DigitProgression::Adaptive {
+                        ui.label("Dos aciertos seguidos suman un dígito; cada fallo resta uno.");
+                        ui.label("La puntuación será tu amplitud de dígitos estimada.");
+                    }
                     ui.label(format!("Dificultad: {}", Self::get_difficulty_name(&self.config.difficulty)));
                     ui.label(format!("Rango de dígitos: {}", match self.config.difficulty {
                         Difficulty::Easy => "1-6",
@@ -303,11 +428,13 @@ impl Game for ReadingSpeedGame {
                 if ui.button("Comenzar").clicked() {
                     let (digit_count, display_time) = Self::get_config_params(&self.config);
                     self.base_digit_count = digit_count;
+                    self.staircase = Staircase::new(digit_count);
                     self.digit_count = digit_count;
                     self.display_time = display_time;
                     self.number_to_remember = Self::generate_number(digit_count);
                     self.state = NumberState::ShowingNumber;
                     self.start_time = Some(Instant::now());
+                    self.overall_start = Some(Instant::now());
                 }
             }
             
@@ -333,7 +460,7 @@ impl Game for ReadingSpeedGame {
                         ui.label(format!("Tiempo restante: {}ms", remaining_millis));
                         ui.add_space(30.0);
                         
-                        ui.label(RichText::new(&self.number_to_remember)
+                        ui.label(RichText::new(self.chunking.apply(&self.number_to_remember))
                             .size(48.0)
                             .color(egui::Color32::from_rgb(50, 50, 200))
                             .strong());
@@ -341,9 +468,16 @@ impl Game for ReadingSpeedGame {
                         ui.add_space(20.0);
                         ui.label(format!("Memoriza este número de {} dígitos", self.digit_count));
                         
-                        if self.training_mode {
-                            ui.label(format!("Training - Ronda {}: {} dígitos", 
-                                self.current_round, self.digit_count));
+                        match self.progression {
+                            DigitProgression::Random => {}
+                            DigitProgression::Training => {
+                                ui.label(format!("Training - Ronda {}: {} dígitos", 
+                                    self.current_round, self.digit_count));
+                            }
+                            DigitProgression::Adaptive => {
+                                ui.label(format!("Adaptativo - Amplitud estimada: {:.1} dígitos", 
+                                    self.staircase.estimate()));
+                            }
                         }
                         
                         if !self.round_results.is_empty() {
@@ -366,6 +500,9 @@ impl Game for ReadingSpeedGame {
                 ui.add_space(20.0);
                 
                 ui.label(format!("El número tenía {} dígitos:", self.digit_count));
+                if self.order == RecallOrder::Backward {
+                    ui.label(RichText::new("Escríbelo en orden inverso, del último dígito al primero").strong());
+                }
                 ui.add_space(10.0);
                 
                 ui.horizontal(|ui| {
@@ -385,7 +522,7 @@ impl Game for ReadingSpeedGame {
                 ui.add_space(20.0);
                 
                 if !self.user_input.is_empty() {
-                    ui.label(format!("Dígitos ingresados: {}/{}", self.user_input.len(), self.digit_count));
+                    ui.label(format!("Dígitos ingresados: {}/{}", self.answer().len(), self.digit_count));
                 }
                 
                 if !self.round_results.is_empty() {
@@ -403,11 +540,17 @@ impl Game for ReadingSpeedGame {
                         ui.separator();
                         ui.label("Último resultado:");
                         ui.label(format!("Número mostrado: {}", last.number));
+                        if self.order == RecallOrder::Backward {
+                            ui.label(format!("Respuesta esperada: {}", last.number.chars().rev().collect::<String>()));
+                        }
                         ui.label(format!("Tu respuesta: {}", last.user_answer));
                         ui.label(format!(
                             "Resultado: {}",
                             if last.correct { "✅ Correcto" } else { "❌ Incorrecto" }
                         ));
+                        if !last.correct {
+                            ui.label(format!("Precisión: {:.0}% ({})", last.accuracy, last.errors.describe()));
+                        }
                     }
                 }
                 
@@ -436,14 +579,33 @@ impl Game for ReadingSpeedGame {
         }
         
         let overall_accuracy = self.calculate_overall_accuracy();
+        let span_estimate = (self.progression == DigitProgression::Adaptive)
+            .then(|| self.staircase.estimate());
+        // En orden inverso la puntuación es siempre un span (en dígitos): la
+        // estimación adaptativa o la cifra más larga repetida sin fallos
+        let score = match (self.order, span_estimate) {
+            (_, Some(span)) => span,
+            (RecallOrder::Backward, None) => self.round_results
+                .iter()
+                .filter(|r| r.correct)
+                .map(|r| r.digit_count as f32)
+                .fold(0.0, f32::max),
+            (RecallOrder::Forward, None) => overall_accuracy,
+        };
         
         Some(GameResult {
             game_type: crate::GameType::ReadingSpeed,
-            score: overall_accuracy,
+            score,
             details: GameDetails::ReadingSpeed {
                 words_correct: self.correct_answers,
                 total_words: self.total_rounds,
-                time_taken: self.start_time?.elapsed(),
+                time_taken: self.overall_start?.elapsed(),
+                rounds: self.round_results.clone(),
+                errors: self.total_errors(),
+                progression: self.progression,
+                span_estimate,
+                order: self.order,
+                chunking: self.chunking,
             },
             timestamp: std::time::SystemTime::now(),
         })
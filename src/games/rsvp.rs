@@ -0,0 +1,153 @@
+//! Lectura RSVP (presentación visual serial rápida)
+//!
+//! Muestra el texto de a una palabra (o grupo de palabras) en el mismo punto
+//! de la pantalla, alineando la letra de reconocimiento óptimo con el centro.
+
+use egui::{Align2, Color32, FontId, Key};
+use std::time::{Duration, Instant};
+
+pub struct RsvpReader {
+    chunks: Vec<String>,
+    index: usize,
+    wpm: u32,
+    chunk_start: Instant,
+    /// Tiempo ya mostrado del grupo actual cuando se pausó
+    paused_elapsed: Option<Duration>,
+}
+
+/// Grupos que se retroceden con la flecha izquierda
+const REWIND_CHUNKS: usize = 5;
+
+impl RsvpReader {
+    pub fn new(text: &str, wpm: u32, chunk_size: usize) -> Self {
+        let words: Vec<&str> = text.split_whitespace().collect();
+        let chunks = words
+            .chunks(chunk_size.max(1))
+            .map(|chunk| chunk.join(" "))
+            .collect();
+
+        Self {
+            chunks,
+            index: 0,
+            wpm: wpm.max(1),
+            chunk_start: Instant::now(),
+            paused_elapsed: None,
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.index >= self.chunks.len()
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused_elapsed.is_some()
+    }
+
+    /// Tiempo de permanencia del grupo: proporcional a sus palabras, más largo
+    /// al final de frase o tras una pausa y para palabras largas.
+    fn dwell(&self, chunk: &str) -> Duration {
+        let words = chunk.split_whitespace().count().max(1);
+        let mut factor = words as f32;
+
+        match chunk.chars().last() {
+            Some('.' | '!' | '?' | '…' | ':') => factor += 1.0,
+            Some(',' | ';' | ')' | '»' | '"') => factor += 0.5,
+            _ => {}
+        }
+        if chunk.split_whitespace().any(|w| w.chars().count() > 8) {
+            factor += 0.2;
+        }
+
+        Duration::from_secs_f32(60.0 / self.wpm as f32 * factor)
+    }
+
+    /// Posición de la letra de reconocimiento óptimo, algo a la izquierda del centro
+    fn orp_index(chunk: &str) -> usize {
+        let len = chunk.chars().count();
+        match len {
+            0..=1 => 0,
+            2..=5 => 1,
+            6..=9 => 2,
+            10..=13 => 3,
+            _ => len * 35 / 100,
+        }
+    }
+
+    fn toggle_pause(&mut self) {
+        match self.paused_elapsed.take() {
+            Some(elapsed) => self.chunk_start = Instant::now() - elapsed,
+            None => self.paused_elapsed = Some(self.chunk_start.elapsed()),
+        }
+    }
+
+    fn rewind(&mut self) {
+        self.index = self.index.saturating_sub(REWIND_CHUNKS);
+        self.chunk_start = Instant::now();
+        if self.paused_elapsed.is_some() {
+            self.paused_elapsed = Some(Duration::ZERO);
+        }
+    }
+
+    fn advance(&mut self) {
+        if let Some(chunk) = self.chunks.get(self.index) {
+            if self.paused_elapsed.is_none() && self.chunk_start.elapsed() >= self.dwell(chunk) {
+                self.index += 1;
+                self.chunk_start = Instant::now();
+            }
+        }
+    }
+
+    pub fn update(&mut self, ui: &mut egui::Ui) {
+        if ui.input(|i| i.key_pressed(Key::Space)) {
+            self.toggle_pause();
+        }
+        if ui.input(|i| i.key_pressed(Key::ArrowLeft)) {
+            self.rewind();
+        }
+        self.advance();
+
+        ui.horizontal(|ui| {
+            let label = if self.is_paused() { "▶ Continuar" } else { "⏸ Pausa" };
+            if ui.button(label).clicked() {
+                self.toggle_pause();
+            }
+            if ui.button("⏪ Retroceder").clicked() {
+                self.rewind();
+            }
+            ui.label(format!(
+                "{} ppm · {} / {}",
+                self.wpm,
+                self.index.min(self.chunks.len()),
+                self.chunks.len()
+            ));
+        });
+        ui.label(egui::RichText::new("Espacio: pausa · ←: retroceder").small());
+        ui.add_space(20.0);
+
+        let (rect, _) = ui.allocate_exact_size(egui::vec2(ui.available_width(), 140.0), egui::Sense::hover());
+        let painter = ui.painter_at(rect);
+        let center = rect.center();
+        let guide = ui.visuals().weak_text_color();
+
+        // Marcas de fijación encima y debajo del punto de reconocimiento
+        painter.line_segment([egui::pos2(center.x, rect.top() + 20.0), egui::pos2(center.x, center.y - 30.0)], (1.0, guide));
+        painter.line_segment([egui::pos2(center.x, center.y + 30.0), egui::pos2(center.x, rect.bottom() - 20.0)], (1.0, guide));
+
+        let Some(chunk) = self.chunks.get(self.index) else {
+            return;
+        };
+
+        let font = FontId::monospace(40.0);
+        let chars: Vec<char> = chunk.chars().collect();
+        let orp = Self::orp_index(chunk).min(chars.len().saturating_sub(1));
+        let before: String = chars[..orp].iter().collect();
+        let focus: String = chars.get(orp).map(|c| c.to_string()).unwrap_or_default();
+        let after: String = chars.get(orp + 1..).map(|s| s.iter().collect()).unwrap_or_default();
+
+        let text_color = ui.visuals().strong_text_color();
+        let focus_width = ui.fonts(|f| f.layout_no_wrap(focus.clone(), font.clone(), text_color).size().x);
+        painter.text(egui::pos2(center.x - focus_width / 2.0, center.y), Align2::RIGHT_CENTER, before, font.clone(), text_color);
+        painter.text(center, Align2::CENTER_CENTER, focus, font.clone(), Color32::from_rgb(200, 50, 50));
+        painter.text(egui::pos2(center.x + focus_width / 2.0, center.y), Align2::LEFT_CENTER, after, font, text_color);
+    }
+}
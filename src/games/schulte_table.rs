@@ -0,0 +1,248 @@
+use super::*;
+use egui::RichText;
+use rand::seq::SliceRandom;
+use std::time::{Duration, Instant};
+
+pub struct SchulteTableGame {
+    config: GameConfig,
+    state: SchulteState,
+    /// Posición en el orden (desde 0) del símbolo de cada celda
+    cells: Vec<usize>,
+    next_target: usize,
+    start_time: Option<Instant>,
+    last_hit: Option<Instant>,
+    click_latencies: Vec<Duration>,
+    errors: usize,
+    /// Celda equivocada y cuándo se pulsó, para marcarla un instante en rojo
+    wrong_cell: Option<(usize, Instant)>,
+    time_taken: Duration,
+    finished: bool,
+    should_go_to_menu: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum SchulteState {
+    Instructions,
+    Playing,
+}
+
+/// Tiempo que una celda equivocada queda marcada en rojo
+const WRONG_FLASH: Duration = Duration::from_millis(300);
+
+impl SchulteTableGame {
+    pub fn new(config: GameConfig) -> Self {
+        Self {
+            config,
+            state: SchulteState::Instructions,
+            cells: Vec::new(),
+            next_target: 0,
+            start_time: None,
+            last_hit: None,
+            click_latencies: Vec::new(),
+            errors: 0,
+            wrong_cell: None,
+            time_taken: Duration::ZERO,
+            finished: false,
+            should_go_to_menu: false,
+        }
+    }
+
+    fn grid_size(&self) -> usize {
+        self.config.schulte_size.clamp(3, self.config.schulte_symbols.max_grid_size())
+    }
+
+    fn generate_cells(size: usize) -> Vec<usize> {
+        let mut cells: Vec<usize> = (0..size * size).collect();
+        cells.shuffle(&mut rand::thread_rng());
+        cells
+    }
+
+    fn click_cell(&mut self, cell: usize) {
+        if self.cells[cell] == self.next_target {
+            let now = Instant::now();
+            if let Some(last) = self.last_hit {
+                self.click_latencies.push(now - last);
+            }
+            self.last_hit = Some(now);
+            self.next_target += 1;
+
+            if self.next_target >= self.cells.len() {
+                self.time_taken = self.start_time.map(|s| s.elapsed()).unwrap_or_default();
+                self.finished = true;
+            }
+        } else if self.cells[cell] > self.next_target {
+            self.errors += 1;
+            self.wrong_cell = Some((cell, Instant::now()));
+        }
+    }
+
+    fn draw_menu_button(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            if ui.button("< Menú").clicked() {
+                self.should_go_to_menu = true;
+                self.state = SchulteState::Instructions;
+            }
+        });
+        ui.separator();
+        ui.add_space(10.0);
+    }
+}
+
+impl Game for SchulteTableGame {
+    fn update(&mut self, ui: &mut egui::Ui, _ctx: &egui::Context) {
+        if self.should_go_to_menu { return; }
+
+        match self.state {
+            SchulteState::Instructions => {
+                self.draw_menu_button(ui);
+                ui.heading("Tabla de Schulte");
+                ui.separator();
+                ui.add_space(10.0);
+
+                ui.group(|ui| {
+                    ui.label("📋 Instrucciones:");
+                    ui.label("1. Fija la vista en el punto rojo del centro de la tabla");
+                    ui.label("2. Sin mover los ojos, busca los símbolos en orden (1, 2, 3... o A, B, C...)");
+                    ui.label("3. Haz clic en cada uno lo más rápido posible");
+                    ui.label("4. Así se entrena la visión periférica y se amplía el campo de lectura");
+                });
+
+                ui.add_space(20.0);
+
+                ui.group(|ui| {
+                    ui.label("⚙️ Configuración:");
+                    ui.add_space(10.0);
+
+                    ui.horizontal(|ui| {
+                        ui.label("Símbolos:");
+                        for symbols in [SchulteSymbols::Numbers, SchulteSymbols::Letters] {
+                            let name = symbols.name().to_string();
+                            ui.radio_value(&mut self.config.schulte_symbols, symbols, name);
+                        }
+                    });
+
+                    ui.add_space(10.0);
+                    let max_size = self.config.schulte_symbols.max_grid_size();
+                    ui.horizontal(|ui| {
+                        ui.label("Tamaño:");
+                        for size in 3..=7 {
+                            ui.add_enabled_ui(size <= max_size, |ui| {
+                                ui.radio_value(&mut self.config.schulte_size, size, format!("{}×{}", size, size));
+                            });
+                        }
+                    });
+                    self.config.schulte_size = self.grid_size();
+
+                    ui.add_space(10.0);
+                    ui.label(format!("Celdas: {}", self.grid_size() * self.grid_size()));
+                });
+
+                ui.add_space(20.0);
+                if ui.button("Comenzar").clicked() {
+                    let size = self.grid_size();
+                    self.cells = Self::generate_cells(size);
+                    self.next_target = 0;
+                    self.errors = 0;
+                    self.click_latencies.clear();
+                    self.start_time = Some(Instant::now());
+                    self.last_hit = self.start_time;
+                    self.state = SchulteState::Playing;
+                }
+            }
+
+            SchulteState::Playing => {
+                self.draw_menu_button(ui);
+
+                let size = self.grid_size();
+                let symbols = self.config.schulte_symbols;
+                let elapsed = self.start_time.map(|s| s.elapsed()).unwrap_or_default();
+                if self.wrong_cell.is_some_and(|(_, at)| at.elapsed() >= WRONG_FLASH) {
+                    self.wrong_cell = None;
+                }
+
+                ui.horizontal(|ui| {
+                    ui.label(format!("Busca: {}", symbols.symbol(self.next_target)));
+                    ui.add_space(20.0);
+                    ui.label(format!("Tiempo: {:.1} s", elapsed.as_secs_f32()));
+                    ui.add_space(20.0);
+                    ui.label(format!("Errores: {}", self.errors));
+                });
+                ui.add_space(10.0);
+
+                let cell_size = (ui.available_height() - 20.0).min(ui.available_width()).min(420.0) / size as f32;
+                let mut clicked = None;
+                let grid = ui.vertical_centered(|ui| {
+                    egui::Grid::new("schulte_grid")
+                        .spacing([2.0, 2.0])
+                        .show(ui, |ui| {
+                            for (i, &cell) in self.cells.iter().enumerate() {
+                                let found = cell < self.next_target;
+                                let mut text = RichText::new(symbols.symbol(cell)).size(cell_size * 0.4);
+                                if found {
+                                    text = text.weak();
+                                }
+                                let mut button = egui::Button::new(text);
+                                if self.wrong_cell.is_some_and(|(wrong, _)| wrong == i) {
+                                    button = button.fill(egui::Color32::from_rgb(200, 50, 50));
+                                }
+                                if ui.add_sized([cell_size, cell_size], button).clicked() {
+                                    clicked = Some(i);
+                                }
+                                if i % size == size - 1 {
+                                    ui.end_row();
+                                }
+                            }
+                        })
+                        .response
+                        .rect
+                });
+
+                // Punto de fijación en el centro de la tabla
+                ui.painter().circle_filled(grid.inner.center(), 4.0, egui::Color32::from_rgb(220, 30, 30));
+
+                if let Some(cell) = clicked {
+                    self.click_cell(cell);
+                }
+            }
+        }
+    }
+
+    fn get_state(&self) -> GameState {
+        if self.finished {
+            GameState::Finished
+        } else if self.should_go_to_menu {
+            GameState::Aborted
+        } else {
+            GameState::Playing
+        }
+    }
+
+    fn get_result(&self) -> Option<GameResult> {
+        if !self.finished { return None; }
+
+        // Celdas por minuto: más alto es mejor, como en el resto de juegos
+        let minutes = self.time_taken.as_secs_f32() / 60.0;
+        let score = if minutes > 0.0 { self.cells.len() as f32 / minutes } else { 0.0 };
+
+        Some(GameResult {
+            game_type: crate::GameType::SchulteTable,
+            score,
+            details: GameDetails::SchulteTable {
+                grid_size: self.grid_size(),
+                symbols: self.config.schulte_symbols,
+                time_taken: self.time_taken,
+                errors: self.errors,
+                click_latencies: self.click_latencies.clone(),
+            },
+            timestamp: std::time::SystemTime::now(),
+        })
+    }
+
+    fn needs_repaint(&self) -> bool {
+        matches!(self.state, SchulteState::Playing) && !self.finished && !self.should_go_to_menu
+    }
+
+    fn get_config(&self) -> Option<&GameConfig> {
+        Some(&self.config)
+    }
+}
@@ -0,0 +1,390 @@
+use super::*;
+use crate::utils::normalize::normalize;
+use egui::{Align2, Color32, FontId, RichText};
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::time::{Duration, Instant};
+
+pub struct TachistoscopeGame {
+    state: FlashState,
+    stimulus: FlashStimulus,
+    total_trials: usize,
+    width: usize,
+    exposure: Duration,
+    left: String,
+    right: String,
+    phase_start: Option<Instant>,
+    first_frame: Option<Instant>,
+    frames: u32,
+    shown_for: Duration,
+    user_input: String,
+    trials: Vec<FlashTrial>,
+    finished: bool,
+    should_go_to_menu: bool,
+    focus_input: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum FlashState {
+    Instructions,
+    Fixation,
+    Flash,
+    Mask,
+    Answer,
+}
+
+const FIXATION_TIME: Duration = Duration::from_millis(800);
+const MASK_TIME: Duration = Duration::from_millis(150);
+const MIN_EXPOSURE: Duration = Duration::from_millis(20);
+const MAX_EXPOSURE: Duration = Duration::from_millis(500);
+const MAX_WIDTH: usize = 14;
+
+const WORDS: &[&str] = &[
+    "sol", "mar", "pan", "luz", "casa", "mesa", "gato", "perro", "libro", "agua",
+    "nube", "río", "flor", "tren", "llave", "piedra", "campo", "fuego", "lápiz", "verde",
+    "noche", "cielo", "calle", "barco", "silla", "papel", "playa", "reloj", "puerta", "árbol",
+];
+
+impl TachistoscopeGame {
+    pub fn new() -> Self {
+        Self {
+            state: FlashState::Instructions,
+            stimulus: FlashStimulus::Digits,
+            total_trials: 20,
+            width: 3,
+            exposure: Duration::from_millis(150),
+            left: String::new(),
+            right: String::new(),
+            phase_start: None,
+            first_frame: None,
+            frames: 0,
+            shown_for: Duration::ZERO,
+            user_input: String::new(),
+            trials: Vec::new(),
+            finished: false,
+            should_go_to_menu: false,
+            focus_input: false,
+        }
+    }
+
+    /// Genera el estímulo de la anchura actual. En dígitos, la anchura es la
+    /// cantidad de cifras; en palabras, la separación entre las dos palabras.
+    fn generate_stimulus(&mut self) {
+        let mut rng = rand::thread_rng();
+        match self.stimulus {
+            FlashStimulus::Digits => {
+                let digits: String = (0..self.width).map(|_| char::from(b'0' + rng.gen_range(0..10))).collect();
+                let split = self.width.div_ceil(2);
+                self.left = digits[..split].to_string();
+                self.right = digits[split..].to_string();
+            }
+            FlashStimulus::Words => {
+                let mut words = WORDS.choose_multiple(&mut rng, 2);
+                self.left = words.next().copied().unwrap_or_default().to_string();
+                self.right = words.next().copied().unwrap_or_default().to_string();
+            }
+        }
+    }
+
+    /// Anchura mínima: dos cifras, o dos palabras juntas (separación 1)
+    fn min_width(&self) -> usize {
+        match self.stimulus {
+            FlashStimulus::Digits => 2,
+            FlashStimulus::Words => 1,
+        }
+    }
+
+    fn start_trial(&mut self) {
+        self.generate_stimulus();
+        self.user_input.clear();
+        self.first_frame = None;
+        self.frames = 0;
+        self.phase_start = Some(Instant::now());
+        self.state = FlashState::Fixation;
+    }
+
+    fn is_correct(&self) -> bool {
+        match self.stimulus {
+            FlashStimulus::Digits => {
+                let answer: String = self.user_input.chars().filter(|c| !c.is_whitespace()).collect();
+                answer == format!("{}{}", self.left, self.right)
+            }
+            FlashStimulus::Words => {
+                normalize(&self.user_input) == normalize(&format!("{} {}", self.left, self.right))
+            }
+        }
+    }
+
+    /// Acierto: más anchura (o menos tiempo si ya está al máximo).
+    /// Fallo: menos anchura y más tiempo de exposición.
+    fn adapt(&mut self, correct: bool) {
+        if correct {
+            if self.width < MAX_WIDTH {
+                self.width += 1;
+            } else {
+                self.exposure = self.exposure.saturating_sub(Duration::from_millis(10)).max(MIN_EXPOSURE);
+            }
+        } else {
+            self.width = self.width.saturating_sub(1).max(self.min_width());
+            self.exposure = (self.exposure + Duration::from_millis(20)).min(MAX_EXPOSURE);
+        }
+    }
+
+    fn submit_answer(&mut self) {
+        let correct = self.is_correct();
+        self.trials.push(FlashTrial {
+            left: self.left.clone(),
+            right: self.right.clone(),
+            answer: self.user_input.trim().to_string(),
+            correct,
+            width: self.width,
+            exposure: self.exposure,
+            shown_for: self.shown_for,
+            frames: self.frames,
+        });
+        self.adapt(correct);
+
+        if self.trials.len() >= self.total_trials {
+            self.finished = true;
+        } else {
+            self.start_trial();
+        }
+    }
+
+    fn max_width(&self) -> usize {
+        self.trials.iter().filter(|t| t.correct).map(|t| t.width).max().unwrap_or(0)
+    }
+
+    /// Separación en píxeles entre el punto de fijación y cada mitad
+    fn gap(&self) -> f32 {
+        match self.stimulus {
+            FlashStimulus::Digits => 14.0,
+            FlashStimulus::Words => 14.0 + 22.0 * self.width as f32,
+        }
+    }
+
+    fn draw_stimulus(&self, ui: &mut egui::Ui, visible: bool, mask: bool) {
+        let (rect, _) = ui.allocate_exact_size(egui::vec2(ui.available_width(), 160.0), egui::Sense::hover());
+        let painter = ui.painter_at(rect);
+        let center = rect.center();
+        let font = FontId::monospace(36.0);
+        let color = ui.visuals().strong_text_color();
+
+        painter.circle_filled(center, 4.0, Color32::from_rgb(220, 30, 30));
+
+        if visible {
+            let gap = self.gap();
+            painter.text(center - egui::vec2(gap, 0.0), Align2::RIGHT_CENTER, &self.left, font.clone(), color);
+            painter.text(center + egui::vec2(gap, 0.0), Align2::LEFT_CENTER, &self.right, font, color);
+        } else if mask {
+            // Máscara para borrar la imagen residual del estímulo
+            let gap = self.gap();
+            let left = "#".repeat(self.left.chars().count());
+            let right = "#".repeat(self.right.chars().count());
+            painter.text(center - egui::vec2(gap, 0.0), Align2::RIGHT_CENTER, left, font.clone(), color);
+            painter.text(center + egui::vec2(gap, 0.0), Align2::LEFT_CENTER, right, font, color);
+        }
+    }
+
+    fn draw_menu_button(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            if ui.button("< Menú").clicked() {
+                self.should_go_to_menu = true;
+                self.state = FlashState::Instructions;
+            }
+        });
+        ui.separator();
+        ui.add_space(10.0);
+    }
+}
+
+impl Game for TachistoscopeGame {
+    fn update(&mut self, ui: &mut egui::Ui, ctx: &egui::Context) {
+        if self.should_go_to_menu { return; }
+
+        match self.state {
+            FlashState::Instructions => {
+                self.draw_menu_button(ui);
+                ui.heading("Taquistoscopio");
+                ui.separator();
+                ui.add_space(10.0);
+
+                ui.group(|ui| {
+                    ui.label("📋 Instrucciones:");
+                    ui.label("1. Mira fijamente el punto rojo del centro");
+                    ui.label("2. Durante un instante aparecerá algo a su izquierda y a su derecha");
+                    ui.label("3. Escribe lo que viste (las dos palabras separadas por un espacio)");
+                    ui.label("4. Si aciertas, el estímulo se ensancha; al llegar al máximo, se acorta el tiempo");
+                });
+
+                ui.add_space(20.0);
+
+                ui.group(|ui| {
+                    ui.label("⚙️ Configuración:");
+                    ui.add_space(10.0);
+
+                    ui.horizontal(|ui| {
+                        ui.label("Estímulo:");
+                        for stimulus in [FlashStimulus::Digits, FlashStimulus::Words] {
+                            let name = stimulus.name().to_string();
+                            ui.radio_value(&mut self.stimulus, stimulus, name);
+                        }
+                    });
+
+                    ui.add_space(10.0);
+                    ui.horizontal(|ui| {
+                        ui.label("Exposición inicial:");
+                        let mut millis = self.exposure.as_millis() as u64;
+                        if ui.add(egui::Slider::new(&mut millis, 20..=500).text("ms")).changed() {
+                            self.exposure = Duration::from_millis(millis);
+                        }
+                    });
+
+                    ui.add_space(10.0);
+                    ui.horizontal(|ui| {
+                        ui.label("Intentos:");
+                        ui.radio_value(&mut self.total_trials, 10, "10");
+                        ui.radio_value(&mut self.total_trials, 20, "20");
+                        ui.radio_value(&mut self.total_trials, 30, "30");
+                    });
+                });
+
+                ui.add_space(20.0);
+                if ui.button("Comenzar").clicked() {
+                    self.width = match self.stimulus {
+                        FlashStimulus::Digits => 3,
+                        FlashStimulus::Words => 1,
+                    };
+                    self.trials.clear();
+                    self.start_trial();
+                }
+            }
+
+            FlashState::Fixation => {
+                self.draw_menu_button(ui);
+                ui.vertical_centered(|ui| {
+                    ui.heading(format!("Intento {} de {}", self.trials.len() + 1, self.total_trials));
+                    ui.label("Mira el punto rojo");
+                });
+                self.draw_stimulus(ui, false, false);
+
+                if self.phase_start.is_some_and(|s| s.elapsed() >= FIXATION_TIME) {
+                    self.state = FlashState::Flash;
+                }
+            }
+
+            FlashState::Flash => {
+                self.draw_menu_button(ui);
+                ui.vertical_centered(|ui| {
+                    ui.heading(format!("Intento {} de {}", self.trials.len() + 1, self.total_trials));
+                    ui.label(" ");
+                });
+
+                // El estímulo se ve desde que se presenta el primer frame hasta que se
+                // presenta el siguiente sin él. Se oculta cuando un frame más pasaría
+                // del tiempo pedido por más de medio frame.
+                let now = Instant::now();
+                let first = *self.first_frame.get_or_insert(now);
+                let shown = now - first;
+                let frame_time = ctx.input(|i| i.stable_dt).max(0.001);
+                if self.frames > 0 && shown.as_secs_f32() + frame_time / 2.0 >= self.exposure.as_secs_f32() {
+                    self.shown_for = shown;
+                    self.phase_start = Some(now);
+                    self.state = FlashState::Mask;
+                    self.draw_stimulus(ui, false, true);
+                } else {
+                    self.frames += 1;
+                    self.draw_stimulus(ui, true, false);
+                }
+            }
+
+            FlashState::Mask => {
+                self.draw_menu_button(ui);
+                ui.vertical_centered(|ui| {
+                    ui.heading(format!("Intento {} de {}", self.trials.len() + 1, self.total_trials));
+                    ui.label(" ");
+                });
+                self.draw_stimulus(ui, false, true);
+
+                if self.phase_start.is_some_and(|s| s.elapsed() >= MASK_TIME) {
+                    self.state = FlashState::Answer;
+                    self.focus_input = true;
+                }
+            }
+
+            FlashState::Answer => {
+                self.draw_menu_button(ui);
+                ui.vertical_centered(|ui| {
+                    ui.heading(format!("Intento {} de {}", self.trials.len() + 1, self.total_trials));
+                    ui.add_space(20.0);
+                    ui.label("¿Qué viste?");
+                    ui.add_space(10.0);
+
+                    let text_edit = egui::TextEdit::singleline(&mut self.user_input)
+                        .desired_width(300.0)
+                        .font(egui::TextStyle::Heading);
+                    let response = ui.add(text_edit);
+                    if self.focus_input {
+                        response.request_focus();
+                        self.focus_input = false;
+                    }
+
+                    if let Some(last) = self.trials.last() {
+                        ui.add_space(20.0);
+                        ui.label(format!(
+                            "Anterior: {} «{} {}» ({} ms, {} frames)",
+                            if last.correct { "✅" } else { "❌" },
+                            last.left,
+                            last.right,
+                            last.shown_for.as_millis(),
+                            last.frames
+                        ));
+                    }
+                    ui.label(RichText::new(format!(
+                        "Anchura: {} · Exposición: {} ms",
+                        self.width,
+                        self.exposure.as_millis()
+                    )).small());
+                });
+
+                ui.add_space(20.0);
+                if button_with_enter(ui, "Responder") {
+                    self.submit_answer();
+                }
+            }
+        }
+    }
+
+    fn get_state(&self) -> GameState {
+        if self.finished {
+            GameState::Finished
+        } else if self.should_go_to_menu {
+            GameState::Aborted
+        } else {
+            GameState::Playing
+        }
+    }
+
+    fn get_result(&self) -> Option<GameResult> {
+        if !self.finished { return None; }
+
+        let max_width = self.max_width();
+
+        Some(GameResult {
+            game_type: crate::GameType::Tachistoscope,
+            score: max_width as f32,
+            details: GameDetails::Tachistoscope {
+                stimulus: self.stimulus,
+                trials: self.trials.clone(),
+                max_width,
+            },
+            timestamp: std::time::SystemTime::now(),
+        })
+    }
+
+    /// Se redibuja en cada frame para que la exposición dure lo pedido
+    fn needs_repaint(&self) -> bool {
+        matches!(self.state, FlashState::Fixation | FlashState::Flash | FlashState::Mask)
+            && !self.should_go_to_menu
+    }
+}
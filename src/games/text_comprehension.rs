@@ -1,15 +1,41 @@
 use super::*;
-use std::time::Instant;
+use super::pacer::PacerReader;
+use super::rsvp::RsvpReader;
+use crate::models::{BookProgress, ChunkLog, ClozeBlank, GameStorage, Passage, PassageLibrary, Question, QuestionKind, CLOZE_GAP};
+use crate::utils::readability::Readability;
+use crate::views::line_chart;
+use rand::seq::SliceRandom;
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::time::{Duration, Instant, SystemTime};
 
 pub struct TextComprehensionGame {
-    #[allow(dead_code)]
     config: GameConfig,
     state: ComprehensionState,
     start_time: Option<Instant>,
+    reading_time: Duration,
+    rsvp: Option<RsvpReader>,
+    pacer: Option<PacerReader>,
+    guide_wpm: Option<f32>,
+    library: PassageLibrary,
+    /// Ids de los textos leídos, del más reciente al más antiguo
+    history: Vec<String>,
+    /// Partes, en orden, del archivo importado que se está leyendo
+    source_ids: Vec<String>,
+    source_title: String,
+    /// Parte del archivo importado elegida para esta partida
+    current_part: Option<usize>,
+    books: BTreeMap<String, BookProgress>,
+    import_path: String,
+    import_error: Option<String>,
+    passage_id: String,
+    title: String,
     text: String,
+    readability: Option<Readability>,
     questions: Vec<Question>,
     current_question: usize,
-    answers: Vec<String>,
+    /// Respuestas por pregunta: el índice elegido o el texto de cada hueco
+    answers: Vec<Vec<String>>,
     finished: bool,
     should_go_to_menu: bool,
 }
@@ -21,113 +47,269 @@ enum ComprehensionState {
     Questions,
 }
 
-#[derive(Debug, Clone)]
-struct Question {
-    question: String,
-    options: Vec<String>,
-    correct_answer: usize,
-}
-
 impl TextComprehensionGame {
-    pub fn new(config: GameConfig) -> Self {
-        let (text, questions) = Self::generate_content(&config);
+    pub fn new(
+        config: GameConfig,
+        mut library: PassageLibrary,
+        history: Vec<String>,
+        books: BTreeMap<String, BookProgress>,
+    ) -> Self {
+        for passage in Self::builtin_passages() {
+            if !library.contains(&passage.id) {
+                library.passages.push(passage);
+            }
+        }
         
-        Self {
+        let mut game = Self {
             config,
             state: ComprehensionState::Instructions,
             start_time: None,
-            text,
-            questions,
+            reading_time: Duration::ZERO,
+            rsvp: None,
+            pacer: None,
+            guide_wpm: None,
+            library,
+            history,
+            source_ids: Vec::new(),
+            source_title: String::new(),
+            current_part: None,
+            books,
+            import_path: String::new(),
+            import_error: None,
+            passage_id: String::new(),
+            title: String::new(),
+            text: String::new(),
+            readability: None,
+            questions: Vec::new(),
             current_question: 0,
             answers: Vec::new(),
             finished: false,
             should_go_to_menu: false,
+        };
+        
+        if let Some(path) = game.config.source_path.clone() {
+            game.import_path = path;
+            game.import_source();
         }
+        game.choose_passage();
+        game
     }
 
-    fn generate_content(config: &GameConfig) -> (String, Vec<Question>) {
-        match config.difficulty {
-            Difficulty::Easy => (
-                "El sol es una estrella muy importante para la vida en la Tierra. Nos da luz y calor todos los días. Sin el sol, no podríamos vivir. Las plantas necesitan la luz del sol para crecer y producir oxígeno que respiramos.".to_string(),
-                vec![
+    /// Importa el archivo de `import_path` y pasa a leerlo por partes
+    fn import_source(&mut self) {
+        let path = self.import_path.trim().to_string();
+        match self.library.import_file(Path::new(&path), self.config.import_passage_words) {
+            Ok((title, ids)) => {
+                self.source_title = title;
+                self.source_ids = ids;
+                self.config.source_path = Some(path);
+                self.import_error = None;
+            }
+            Err(e) => {
+                self.source_ids.clear();
+                self.config.source_path = None;
+                self.import_error = Some(e);
+            }
+        }
+    }
+
+    /// Deja el archivo importado y vuelve a elegir entre todos los textos
+    fn leave_source(&mut self) {
+        self.source_ids.clear();
+        self.config.source_path = None;
+        self.choose_passage();
+    }
+
+    fn source_progress(&self) -> Option<&BookProgress> {
+        self.config.source_path.as_ref().and_then(|path| self.books.get(path))
+    }
+
+    /// Parte por la que va la lectura del archivo importado. Al terminarlo
+    /// se vuelve a empezar por la primera.
+    fn next_source_part(&self) -> usize {
+        let next = self.source_progress().map(|p| p.next_part).unwrap_or(0);
+        if next < self.source_ids.len() { next } else { 0 }
+    }
+
+    fn choose_passage(&mut self) {
+        self.current_part = None;
+        if !self.source_ids.is_empty() {
+            let part = self.next_source_part();
+            let id = &self.source_ids[part];
+            if let Some(passage) = self.library.passages.iter().find(|p| p.id == *id).cloned() {
+                self.set_passage(passage);
+                self.current_part = Some(part);
+            }
+            return;
+        }
+        
+        let recent = &self.history[..self.history.len().min(self.library.passages.len() + 3)];
+        let candidates: Vec<&Passage> = self.library.passages
+            .iter()
+            .filter(|p| p.difficulty == self.config.difficulty)
+            .collect();
+        
+        let fresh: Vec<&Passage> = candidates
+            .iter()
+            .copied()
+            .filter(|p| !recent.contains(&p.id))
+            .collect();
+        
+        let chosen = match fresh.choose(&mut rand::thread_rng()) {
+            Some(passage) => Some(*passage),
+            None => candidates.iter().copied().max_by_key(|p| {
+                recent.iter().position(|id| *id == p.id).unwrap_or(usize::MAX)
+            }),
+        };
+        
+        if let Some(passage) = chosen.cloned() {
+            self.set_passage(passage);
+        }
+    }
+
+    fn set_passage(&mut self, passage: Passage) {
+        self.passage_id = passage.id;
+        self.title = passage.title;
+        self.readability = Some(Readability::analyze(&passage.text));
+        self.text = passage.text;
+        self.questions = passage.questions;
+    }
+
+    fn builtin_passages() -> Vec<Passage> {
+        vec![
+            Passage {
+                id: "builtin-facil".to_string(),
+                title: "El sol".to_string(),
+                difficulty: Difficulty::Easy,
+                text: "El sol es una estrella muy importante para la vida en la Tierra. Nos da luz y calor todos los días. Sin el sol, no podríamos vivir. Las plantas necesitan la luz del sol para crecer y producir oxígeno que respiramos.".to_string(),
+                questions: vec![
                     Question {
                         question: "¿Qué es el sol?".to_string(),
-                        options: vec!["Un planeta".to_string(), "Una estrella".to_string(), "Una luna".to_string()],
-                        correct_answer: 1,
+                        kind: QuestionKind::MultipleChoice {
+                            options: vec!["Un planeta".to_string(), "Una estrella".to_string(), "Una luna".to_string()],
+                            correct_answer: 1,
+                        },
                     },
                     Question {
                         question: "¿Qué necesitan las plantas del sol?".to_string(),
-                        options: vec!["Agua".to_string(), "Luz".to_string(), "Tierra".to_string()],
-                        correct_answer: 1,
+                        kind: QuestionKind::MultipleChoice {
+                            options: vec!["Agua".to_string(), "Luz".to_string(), "Tierra".to_string()],
+                            correct_answer: 1,
+                        },
+                    },
+                    Question {
+                        question: "Las plantas necesitan la ___ del sol para crecer y producir ___.".to_string(),
+                        kind: QuestionKind::Cloze {
+                            blanks: vec![Self::blank("luz", &[]), Self::blank("oxígeno", &[])],
+                        },
                     }
-                ]
-            ),
-            Difficulty::Medium => (
-                "La inteligencia artificial es una tecnología que permite a las máquinas realizar tareas que normalmente requieren inteligencia humana. Incluye el aprendizaje automático, donde los sistemas pueden mejorar su rendimiento a través de la experiencia sin ser programados explícitamente.".to_string(),
-                vec![
+                ],
+            },
+            Passage {
+                id: "builtin-medio".to_string(),
+                title: "La inteligencia artificial".to_string(),
+                difficulty: Difficulty::Medium,
+                text: "La inteligencia artificial es una tecnología que permite a las máquinas realizar tareas que normalmente requieren inteligencia humana. Incluye el aprendizaje automático, donde los sistemas pueden mejorar su rendimiento a través de la experiencia sin ser programados explícitamente.".to_string(),
+                questions: vec![
                     Question {
                         question: "¿Qué permite la inteligencia artificial?".to_string(),
-                        options: vec![
-                            "Solo calcular números".to_string(),
-                            "Realizar tareas que requieren inteligencia humana".to_string(),
-                            "Reemplazar completamente a los humanos".to_string()
-                        ],
-                        correct_answer: 1,
+                        kind: QuestionKind::MultipleChoice {
+                            options: vec![
+                                "Solo calcular números".to_string(),
+                                "Realizar tareas que requieren inteligencia humana".to_string(),
+                                "Reemplazar completamente a los humanos".to_string()
+                            ],
+                            correct_answer: 1,
+                        },
                     },
                     Question {
                         question: "¿Cómo mejoran los sistemas de aprendizaje automático?".to_string(),
-                        options: vec![
-                            "A través de la experiencia".to_string(),
-                            "Solo con programación explícita".to_string(),
-                            "No pueden mejorar".to_string()
-                        ],
-                        correct_answer: 0,
+                        kind: QuestionKind::MultipleChoice {
+                            options: vec![
+                                "A través de la experiencia".to_string(),
+                                "Solo con programación explícita".to_string(),
+                                "No pueden mejorar".to_string()
+                            ],
+                            correct_answer: 0,
+                        },
+                    },
+                    Question {
+                        question: "Los sistemas pueden mejorar su ___ a través de la ___.".to_string(),
+                        kind: QuestionKind::Cloze {
+                            blanks: vec![Self::blank("rendimiento", &["desempeño"]), Self::blank("experiencia", &[])],
+                        },
                     }
-                ]
-            ),
-            Difficulty::Hard => (
-                "La neuroplasticidad se refiere a la capacidad del sistema nervioso para cambiar su estructura y función en respuesta a la experiencia. Este fenómeno permite la adaptación, el aprendizaje y la recuperación tras lesiones cerebrales, desafiando la antigua creencia de que el cerebro adulto era inmutable.".to_string(),
-                vec![
+                ],
+            },
+            Passage {
+                id: "builtin-dificil".to_string(),
+                title: "La neuroplasticidad".to_string(),
+                difficulty: Difficulty::Hard,
+                text: "La neuroplasticidad se refiere a la capacidad del sistema nervioso para cambiar su estructura y función en respuesta a la experiencia. Este fenómeno permite la adaptación, el aprendizaje y la recuperación tras lesiones cerebrales, desafiando la antigua creencia de que el cerebro adulto era inmutable.".to_string(),
+                questions: vec![
                     Question {
                         question: "¿Qué es la neuroplasticidad?".to_string(),
-                        options: vec![
-                            "La rigidez del cerebro".to_string(),
-                            "La capacidad del sistema nervioso para cambiar".to_string(),
-                            "Una enfermedad cerebral".to_string()
-                        ],
-                        correct_answer: 1,
+                        kind: QuestionKind::MultipleChoice {
+                            options: vec![
+                                "La rigidez del cerebro".to_string(),
+                                "La capacidad del sistema nervioso para cambiar".to_string(),
+                                "Una enfermedad cerebral".to_string()
+                            ],
+                            correct_answer: 1,
+                        },
                     },
                     Question {
                         question: "¿Qué creencia antigua desafía la neuroplasticidad?".to_string(),
-                        options: vec![
-                            "Que el cerebro puede cambiar".to_string(),
-                            "Que el cerebro adulto era inmutable".to_string(),
-                            "Que el aprendizaje es imposible".to_string()
-                        ],
-                        correct_answer: 1,
+                        kind: QuestionKind::MultipleChoice {
+                            options: vec![
+                                "Que el cerebro puede cambiar".to_string(),
+                                "Que el cerebro adulto era inmutable".to_string(),
+                                "Que el aprendizaje es imposible".to_string()
+                            ],
+                            correct_answer: 1,
+                        },
+                    },
+                    Question {
+                        question: "Este fenómeno permite la adaptación, el ___ y la recuperación tras lesiones ___.".to_string(),
+                        kind: QuestionKind::Cloze {
+                            blanks: vec![Self::blank("aprendizaje", &[]), Self::blank("cerebrales", &[])],
+                        },
                     }
-                ]
-            ),
+                ],
+            },
+        ]
+    }
+
+    fn blank(answer: &str, synonyms: &[&str]) -> ClozeBlank {
+        ClozeBlank {
+            answer: answer.to_string(),
+            synonyms: synonyms.iter().map(|s| s.to_string()).collect(),
         }
     }
 
+    fn correct_answers(&self) -> usize {
+        self.questions
+            .iter()
+            .zip(&self.answers)
+            .filter(|(question, answers)| question.is_correct(answers))
+            .count()
+    }
+
+    /// Palabras por minuto durante la lectura
+    fn calculate_wpm(&self) -> f32 {
+        let minutes = self.reading_time.as_secs_f32() / 60.0;
+        if minutes <= 0.0 {
+            return 0.0;
+        }
+        self.text.split_whitespace().count() as f32 / minutes
+    }
+
     fn calculate_score(&self) -> f32 {
         if self.questions.is_empty() {
             return 0.0;
         }
         
-        let mut correct = 0;
-        for (i, answer_str) in self.answers.iter().enumerate() {
-            if i < self.questions.len() {
-                if let Ok(answer_idx) = answer_str.parse::<usize>() {
-                    if answer_idx == self.questions[i].correct_answer {
-                        correct += 1;
-                    }
-                }
-            }
-        }
-        
-        (correct as f32 / self.questions.len() as f32) * 100.0
+        (self.correct_answers() as f32 / self.questions.len() as f32) * 100.0
     }
 }
 
@@ -154,7 +336,7 @@ impl Game for TextComprehensionGame {
                     ui.label("📋 Instrucciones:");
                     ui.label("1. Lee el texto cuidadosamente");
                     ui.label("2. Responde las preguntas sobre el texto");
-                    ui.label("3. Selecciona la respuesta correcta");
+                    ui.label("3. Selecciona la respuesta correcta o escribe las palabras que faltan");
                 });
                 
                 ui.add_space(20.0);
@@ -162,14 +344,167 @@ impl Game for TextComprehensionGame {
                 ui.group(|ui| {
                     ui.label("⚙️ Configuración:");
                     ui.add_space(10.0);
+                    
+                    ui.add_enabled_ui(self.source_ids.is_empty(), |ui| {
+                        ui.horizontal(|ui| {
+                            ui.label("Dificultad:");
+                            let mut changed = false;
+                            for difficulty in [Difficulty::Easy, Difficulty::Medium, Difficulty::Hard] {
+                                let name = difficulty.name().to_string();
+                                changed |= ui.radio_value(&mut self.config.difficulty, difficulty, name).changed();
+                            }
+                            if changed {
+                                self.choose_passage();
+                            }
+                        });
+                    });
+                    
+                    ui.add_space(10.0);
+                    ui.horizontal(|ui| {
+                        ui.label("Modo de lectura:");
+                        for mode in ReadingMode::all() {
+                            let name = mode.name().to_string();
+                            ui.radio_value(&mut self.config.reading_mode, mode, name);
+                        }
+                    });
+                    
+                    if self.config.reading_mode != ReadingMode::Normal {
+                        ui.add_space(10.0);
+                        ui.horizontal(|ui| {
+                            ui.label("Velocidad:");
+                            ui.add(egui::Slider::new(&mut self.config.target_wpm, 100..=1000).text("ppm"));
+                        });
+                    }
+                    
+                    if self.config.reading_mode == ReadingMode::Pacer {
+                        ui.horizontal(|ui| {
+                            ui.label("Aceleración:");
+                            ui.add(egui::Slider::new(&mut self.config.pacer_ramp_wpm, 0..=200).text("ppm por minuto"));
+                        });
+                        ui.label("Sigue el subrayado con la vista; su velocidad aumenta poco a poco.");
+                    }
+                    
+                    if self.config.reading_mode == ReadingMode::Rsvp {
+                        ui.horizontal(|ui| {
+                            ui.label("Palabras por vez:");
+                            ui.radio_value(&mut self.config.rsvp_chunk_size, 1, "1");
+                            ui.radio_value(&mut self.config.rsvp_chunk_size, 2, "2");
+                            ui.radio_value(&mut self.config.rsvp_chunk_size, 3, "3");
+                        });
+                        ui.label("Las preguntas empiezan al terminar el texto.");
+                    }
+                    
+                    ui.add_space(10.0);
+                    ui.horizontal(|ui| {
+                        ui.label("Abrir texto o libro (.txt, .md, .epub):");
+                        ui.add(egui::TextEdit::singleline(&mut self.import_path)
+                            .hint_text("ruta del archivo")
+                            .desired_width(300.0));
+                        if ui.button("Importar").clicked() {
+                            self.import_source();
+                            self.choose_passage();
+                        }
+                    });
+                    ui.horizontal(|ui| {
+                        ui.label("Palabras por parte (textos sin capítulos):");
+                        ui.add(egui::Slider::new(&mut self.config.import_passage_words, 100..=1000));
+                    });
+                    if let Some(error) = &self.import_error {
+                        ui.colored_label(egui::Color32::from_rgb(200, 120, 0), error);
+                    }
+                    if !self.source_ids.is_empty() {
+                        let part = self.current_part.unwrap_or(0);
+                        let total = self.source_ids.len();
+                        ui.add_space(10.0);
+                        ui.horizontal(|ui| {
+                            ui.label(format!("📖 {}: parte {} de {}", self.source_title, part + 1, total));
+                            if ui.button("Usar la biblioteca").clicked() {
+                                self.leave_source();
+                            }
+                        });
+                        ui.add(egui::ProgressBar::new(part as f32 / total as f32).desired_width(300.0));
+                        
+                        if let Some(progress) = self.source_progress() {
+                            if progress.is_finished() {
+                                ui.label("📕 Ya terminaste este texto: se empieza de nuevo.");
+                            }
+                            if progress.chunks.len() >= 2 {
+                                let wpm: Vec<f32> = progress.chunks.iter().map(|c| c.wpm).collect();
+                                ui.collapsing("📈 Velocidad por parte", |ui| {
+                                    line_chart(ui, 120.0, &[("Velocidad (ppm)", egui::Color32::from_rgb(50, 120, 200), wpm)]);
+                                });
+                            }
+                        }
+                    }
+                    
+                    let mut others: Vec<(&String, &BookProgress)> = self.books
+                        .iter()
+                        .filter(|(path, _)| Some(*path) != self.config.source_path.as_ref())
+                        .collect();
+                    others.sort_by_key(|(_, progress)| std::cmp::Reverse(progress.last_read()));
+                    if !others.is_empty() {
+                        let mut resume = None;
+                        ui.add_space(10.0);
+                        ui.label("Continuar leyendo:");
+                        for (path, progress) in others.into_iter().take(5) {
+                            ui.horizontal(|ui| {
+                                ui.label(format!(
+                                    "{} · parte {} de {}",
+                                    progress.title,
+                                    (progress.next_part + 1).min(progress.total_parts),
+                                    progress.total_parts
+                                ));
+                                if ui.small_button("Reanudar").clicked() {
+                                    resume = Some(path.clone());
+                                }
+                            });
+                        }
+                        if let Some(path) = resume {
+                            self.import_path = path;
+                            self.import_source();
+                            self.choose_passage();
+                        }
+                    }
+                    
+                    ui.add_space(10.0);
+                    if !self.title.is_empty() {
+                        ui.label(format!("Texto: {}", self.title));
+                    }
+                    if let Some(readability) = &self.readability {
+                        ui.label(format!(
+                            "Legibilidad: {} → {}",
+                            readability.describe(),
+                            readability.difficulty().name()
+                        ));
+                    }
                     ui.label(format!("Preguntas: {}", self.questions.len()));
+                    ui.label(format!("Textos disponibles: {}", self.library.passages.len()));
                 });
                 
+                if !self.library.errors.is_empty() {
+                    ui.add_space(10.0);
+                    ui.collapsing(format!("⚠️ {} problema(s) al cargar textos", self.library.errors.len()), |ui| {
+                        for error in &self.library.errors {
+                            ui.colored_label(egui::Color32::from_rgb(200, 120, 0), error);
+                        }
+                    });
+                }
+                ui.label(egui::RichText::new(format!(
+                    "Añade tus textos como .json, .toml, .txt, .md o .epub en {}",
+                    GameStorage::passages_dir().display()
+                )).small());
+                
                 ui.add_space(20.0);
                 
                 if ui.button("Comenzar").clicked() {
                     self.state = ComprehensionState::Reading;
                     self.start_time = Some(Instant::now());
+                    self.rsvp = (self.config.reading_mode == ReadingMode::Rsvp).then(|| {
+                        RsvpReader::new(&self.text, self.config.target_wpm, self.config.rsvp_chunk_size)
+                    });
+                    self.pacer = (self.config.reading_mode == ReadingMode::Pacer).then(|| {
+                        PacerReader::new(&self.text, self.config.target_wpm, self.config.pacer_ramp_wpm)
+                    });
                 }
             }
             
@@ -185,15 +520,38 @@ impl Game for TextComprehensionGame {
                 ui.heading("Lee el siguiente texto:");
                 ui.separator();
                 
-                egui::ScrollArea::vertical().show(ui, |ui| {
-                    ui.label(egui::RichText::new(&self.text).size(16.0));
-                });
-                
-                ui.add_space(20.0);
+                let done_reading = match (&mut self.rsvp, &mut self.pacer) {
+                    (Some(reader), _) => {
+                        reader.update(ui);
+                        self.guide_wpm = Some(self.config.target_wpm as f32);
+                        reader.is_finished()
+                    }
+                    (None, Some(pacer)) => {
+                        let skip = ui.button("Continuar a las preguntas").clicked();
+                        ui.add_space(10.0);
+                        pacer.update(ui);
+                        self.guide_wpm = Some(pacer.current_wpm());
+                        skip || pacer.is_finished()
+                    }
+                    (None, None) => {
+                        egui::ScrollArea::vertical().show(ui, |ui| {
+                            ui.label(egui::RichText::new(&self.text).size(16.0));
+                        });
+                        
+                        ui.add_space(20.0);
+                        ui.button("Continuar a las preguntas").clicked()
+                    }
+                };
                 
-                if ui.button("Continuar a las preguntas").clicked() {
+                if done_reading {
+                    self.reading_time = self.start_time.map(|s| s.elapsed()).unwrap_or_default();
+                    self.rsvp = None;
+                    self.pacer = None;
                     self.state = ComprehensionState::Questions;
-                    self.answers = vec!["".to_string(); self.questions.len()];
+                    self.answers = self.questions
+                        .iter()
+                        .map(|q| vec![String::new(); q.answer_slots()])
+                        .collect();
                 }
             }
             
@@ -214,15 +572,39 @@ impl Game for TextComprehensionGame {
                 if self.current_question < self.questions.len() {
                     let question = &self.questions[self.current_question];
                     
-                    ui.label(egui::RichText::new(&question.question).size(18.0));
+                    if let QuestionKind::MultipleChoice { .. } = question.kind {
+                        ui.label(egui::RichText::new(&question.question).size(18.0));
+                    }
                     ui.add_space(10.0);
                     
-                    let mut selected = self.answers[self.current_question].parse::<usize>().unwrap_or(usize::MAX);
+                    let answers = &mut self.answers[self.current_question];
                     
-                    for (i, option) in question.options.iter().enumerate() {
-                        if ui.radio(selected == i, option).clicked() {
-                            selected = i;
-                            self.answers[self.current_question] = i.to_string();
+                    match &question.kind {
+                        QuestionKind::MultipleChoice { options, .. } => {
+                            let mut selected = answers[0].parse::<usize>().unwrap_or(usize::MAX);
+                            
+                            for (i, option) in options.iter().enumerate() {
+                                if ui.radio(selected == i, option).clicked() {
+                                    selected = i;
+                                    answers[0] = i.to_string();
+                                }
+                            }
+                        }
+                        QuestionKind::Cloze { .. } => {
+                            ui.label("Escribe las palabras que faltan:");
+                            ui.add_space(10.0);
+                            ui.horizontal_wrapped(|ui| {
+                                for (i, segment) in question.question.split(CLOZE_GAP).enumerate() {
+                                    if i > 0 {
+                                        if let Some(answer) = answers.get_mut(i - 1) {
+                                            ui.add(egui::TextEdit::singleline(answer).desired_width(120.0));
+                                        }
+                                    }
+                                    if !segment.is_empty() {
+                                        ui.label(egui::RichText::new(segment).size(16.0));
+                                    }
+                                }
+                            });
                         }
                     }
                     
@@ -262,14 +644,8 @@ impl Game for TextComprehensionGame {
         }
         
         let score = self.calculate_score();
-        let correct_answers = self.answers.iter().enumerate()
-            .filter(|(i, answer_str)| {
-                if let Ok(answer_idx) = answer_str.parse::<usize>() {
-                    *i < self.questions.len() && answer_idx == self.questions[*i].correct_answer
-                } else {
-                    false
-                }
-            }).count();
+        let wpm = self.calculate_wpm();
+        let correct_answers = self.correct_answers();
         
         Some(GameResult {
             game_type: crate::GameType::TextComprehension,
@@ -277,8 +653,44 @@ impl Game for TextComprehensionGame {
             details: GameDetails::TextComprehension {
                 questions_correct: correct_answers,
                 total_questions: self.questions.len(),
+                passage_id: self.passage_id.clone(),
+                reading_time: self.reading_time,
+                wpm,
+                effective_wpm: wpm * score / 100.0,
+                reading_mode: self.config.reading_mode,
+                guide_wpm: self.guide_wpm,
             },
             timestamp: std::time::SystemTime::now(),
         })
     }
-}
\ No newline at end of file
+
+    fn needs_repaint(&self) -> bool {
+        (self.rsvp.is_some() || self.pacer.is_some()) && !self.finished && !self.should_go_to_menu
+    }
+
+    fn get_config(&self) -> Option<&GameConfig> {
+        Some(&self.config)
+    }
+
+    /// Guarda por dónde va la lectura del archivo importado y la velocidad de la parte
+    fn save_to_storage(&mut self, storage: &mut GameStorage) {
+        let (Some(path), Some(part)) = (&self.config.source_path, self.current_part) else {
+            return;
+        };
+        if !self.finished {
+            return;
+        }
+        
+        let mut progress = self.books.get(path).cloned().unwrap_or_default();
+        progress.title = self.source_title.clone();
+        progress.total_parts = self.source_ids.len();
+        progress.next_part = part + 1;
+        progress.chunks.push(ChunkLog {
+            part,
+            words: self.text.split_whitespace().count(),
+            wpm: self.calculate_wpm(),
+            timestamp: SystemTime::now(),
+        });
+        storage.set_book_progress(path.clone(), progress);
+    }
+}
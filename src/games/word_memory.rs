@@ -1,10 +1,13 @@
 use super::*;
+use crate::models::{WordListLibrary, BUILTIN_LIST};
+use crate::utils::edit_distance::distance;
+use crate::utils::normalize::normalize;
 use rand::seq::SliceRandom;
 use std::time::{Duration, Instant};
 
 pub struct WordMemoryGame {
-    #[allow(dead_code)]
     config: GameConfig,
+    library: WordListLibrary,
     state: MemoryState,
     start_time: Option<Instant>,
     words_to_remember: Vec<String>,
@@ -14,93 +17,334 @@ pub struct WordMemoryGame {
     word_display_time: Duration,
     last_word_time: Option<Instant>,
     focus_input: bool,
-    matched_words: Vec<String>,
+    /// Crédito de cada posición de la lista, calculado al terminar
+    position_scores: Vec<f32>,
+    near_misses: Vec<NearMiss>,
+    intrusions: Vec<String>,
+    /// Orden (índices de pareja) en que se preguntan las parejas
+    pair_order: Vec<usize>,
+    pair_trials: Vec<PairTrial>,
+    cue_start: Option<Instant>,
+    /// Prueba de recuerdo diferido que se está haciendo, si lo es
+    delayed_test: Option<PendingRecall>,
     should_go_to_menu: bool,
 }
 
+/// Marca para una palabra olvidada en el recuerdo en orden
+const SKIP_MARK: &str = "?";
+
 #[derive(Debug, Clone, PartialEq)]
 enum MemoryState {
     Instructions,
     ShowingWords,
     Recall,
+    /// Modo parejas: se muestra la primera palabra y se escribe la segunda
+    CuedRecall,
 }
 
 impl WordMemoryGame {
-    pub fn new(config: GameConfig) -> Self {
-        let words = Self::generate_words(&config);
-        let word_time = match config.difficulty {
-            Difficulty::Easy => Duration::from_millis(3000),
-            Difficulty::Medium => Duration::from_millis(2000),
-            Difficulty::Hard => Duration::from_millis(1500),
-        };
+    pub fn new(mut config: GameConfig, library: WordListLibrary) -> Self {
+        // La lista recordada puede haberse borrado desde la última partida
+        if config.word_list.as_ref().is_some_and(|name| library.get(name).is_none()) {
+            config.word_list = None;
+        }
         
-        Self {
+        let mut game = Self {
             config,
+            library,
             state: MemoryState::Instructions,
             start_time: None,
-            words_to_remember: words,
+            words_to_remember: Vec::new(),
             current_word_index: 0,
             user_input: String::new(),
             finished: false,
-            word_display_time: word_time,
+            word_display_time: Duration::ZERO,
             last_word_time: None,
             focus_input: false,
-            matched_words: Vec::new(),
+            position_scores: Vec::new(),
+            near_misses: Vec::new(),
+            intrusions: Vec::new(),
+            pair_order: Vec::new(),
+            pair_trials: Vec::new(),
+            cue_start: None,
+            delayed_test: None,
             should_go_to_menu: false,
+        };
+        game.generate_words();
+        game
+    }
+
+    /// Prueba de recuerdo diferido: pide directamente las palabras de una
+    /// partida anterior, sin volver a mostrarlas
+    pub fn delayed_test(mut config: GameConfig, pending: PendingRecall) -> Self {
+        config.recall_mode = pending.recall_mode;
+        let mut game = Self::new(config, WordListLibrary::default());
+        game.words_to_remember = pending.stimulus.clone();
+        game.delayed_test = Some(pending);
+        game.start_time = Some(Instant::now());
+        if game.config.recall_mode == RecallMode::Pairs {
+            game.start_cued_recall();
+        } else {
+            game.state = MemoryState::Recall;
+            game.focus_input = true;
         }
+        game
     }
 
-    fn generate_words(config: &GameConfig) -> Vec<String> {
-        let word_pools = match config.difficulty {
-            Difficulty::Easy => vec![
-                "casa", "perro", "sol", "mesa", "libro", "agua", "fuego", "árbol",
-                "flor", "cielo", "mar", "montaña", "río", "piedra", "luz", "noche"
-            ],
-            Difficulty::Medium => vec![
-                "computadora", "teléfono", "automóvil", "biblioteca", "hospital", "universidad",
-                "restaurante", "supermercado", "farmacia", "aeropuerto", "estación", "oficina"
-            ],
-            Difficulty::Hard => vec![
-                "epistemología", "neuroplasticidad", "fenomenología", "hermenéutica", "paradigma",
-                "metamorfosis", "episódico", "cronológico", "metodología", "taxonomía"
-            ],
-        };
-        
-        let word_count = match config.difficulty {
+    fn list_name(&self) -> &str {
+        self.config.word_list.as_deref().unwrap_or(BUILTIN_LIST)
+    }
+
+    /// Elige las palabras de la lista y el tiempo por palabra según la dificultad
+    fn generate_words(&mut self) {
+        let word_count = match self.config.difficulty {
             Difficulty::Easy => 8,
             Difficulty::Medium => 12,
             Difficulty::Hard => 16,
         };
+        self.word_display_time = match self.config.difficulty {
+            Difficulty::Easy => Duration::from_millis(3000),
+            Difficulty::Medium => Duration::from_millis(2000),
+            Difficulty::Hard => Duration::from_millis(1500),
+        };
         
-        let mut words: Vec<String> = word_pools.iter().map(|s| s.to_string()).collect();
-        words.shuffle(&mut rand::thread_rng());
-        words.truncate(word_count);
-        words
+        self.words_to_remember = self.library
+            .get(self.list_name())
+            .map(|list| list.pick_words(&self.config.difficulty, word_count))
+            .unwrap_or_default();
+        if self.config.recall_mode == RecallMode::Pairs {
+            // Dos palabras por pareja, y cada una se ve el doble de tiempo
+            self.words_to_remember.truncate(self.words_to_remember.len() / 2 * 2);
+            self.word_display_time *= 2;
+        }
     }
 
-    fn calculate_accuracy(&self) -> f32 {
-        let user_words: Vec<String> = self.user_input
+    /// Elementos que se muestran uno a uno: palabras, o parejas en el modo parejas
+    fn item_count(&self) -> usize {
+        match self.config.recall_mode {
+            RecallMode::Pairs => self.words_to_remember.len() / 2,
+            _ => self.words_to_remember.len(),
+        }
+    }
+
+    fn item_text(&self, index: usize) -> String {
+        match self.config.recall_mode {
+            RecallMode::Pairs => format!("{} — {}", self.words_to_remember[2 * index], self.words_to_remember[2 * index + 1]),
+            _ => self.words_to_remember[index].clone(),
+        }
+    }
+
+    fn start_cued_recall(&mut self) {
+        self.pair_order = (0..self.item_count()).collect();
+        self.pair_order.shuffle(&mut rand::thread_rng());
+        self.pair_trials.clear();
+        self.user_input.clear();
+        self.state = MemoryState::CuedRecall;
+        self.cue_start = Some(Instant::now());
+        self.focus_input = true;
+    }
+
+    fn submit_pair_answer(&mut self) {
+        let pair = self.pair_order[self.pair_trials.len()];
+        let cue = self.words_to_remember[2 * pair].clone();
+        let target = self.words_to_remember[2 * pair + 1].clone();
+        let answer = self.user_input.trim().to_string();
+        let targets = [target.clone()];
+        let correct = Matcher::new(&targets, self.config.typo_tolerance).compare(0, &answer) != WordMatch::None;
+
+        self.pair_trials.push(PairTrial {
+            cue,
+            target,
+            answer,
+            correct,
+            latency: self.cue_start.map(|t| t.elapsed()).unwrap_or_default(),
+        });
+        self.user_input.clear();
+        self.cue_start = Some(Instant::now());
+        self.focus_input = true;
+        if self.pair_trials.len() == self.pair_order.len() {
+            self.finished = true;
+        }
+    }
+
+    /// Palabras escritas, en minúsculas y sin la puntuación que las rodea
+    fn answer_words(&self) -> Vec<String> {
+        self.user_input
             .split_whitespace()
-            .map(|s| s.to_lowercase())
-            .collect();
-        
-        let original_words: Vec<String> = self.words_to_remember
-            .iter()
-            .map(|s| s.to_lowercase())
-            .collect();
-        
-        let mut correct = 0;
-        for word in &user_words {
-            if original_words.contains(word) {
-                correct += 1;
+            .map(|w| {
+                if w == SKIP_MARK {
+                    w.to_string()
+                } else {
+                    w.trim_matches(|c: char| !c.is_alphanumeric()).to_lowercase()
+                }
+            })
+            .filter(|w| !w.is_empty())
+            .collect()
+    }
+
+    fn score_answers(&mut self) {
+        let answers = self.answer_words();
+        let matcher = Matcher::new(&self.words_to_remember, self.config.typo_tolerance);
+        let recall = match self.config.recall_mode {
+            RecallMode::Free => matcher.free_recall(&answers),
+            RecallMode::Serial | RecallMode::Pairs => matcher.serial_recall(&answers),
+        };
+        self.intrusions = matcher.intrusions(&answers);
+        self.position_scores = recall.scores;
+        self.near_misses = recall.near_misses;
+    }
+
+    fn calculate_accuracy(&self) -> f32 {
+        if self.config.recall_mode == RecallMode::Pairs {
+            let correct = self.pair_trials.iter().filter(|t| t.correct).count();
+            correct as f32 / self.pair_trials.len().max(1) as f32
+        } else if self.position_scores.is_empty() {
+            0.0
+        } else {
+            self.position_scores.iter().sum::<f32>() / self.position_scores.len() as f32
+        }
+    }
+
+    /// Elementos preguntados, respuestas y crédito de cada uno, para la
+    /// prueba diferida. En el modo parejas, cada elemento es una pareja.
+    fn scored_items(&self) -> (Vec<String>, Vec<String>, Vec<f32>) {
+        if self.config.recall_mode == RecallMode::Pairs {
+            (
+                self.pair_trials.iter().map(|t| format!("{} — {}", t.cue, t.target)).collect(),
+                self.pair_trials.iter().map(|t| t.answer.clone()).collect(),
+                self.pair_trials.iter().map(|t| if t.correct { 1.0 } else { 0.0 }).collect(),
+            )
+        } else {
+            (self.words_to_remember.clone(), self.answer_words(), self.position_scores.clone())
+        }
+    }
+}
+
+/// Cómo se parece una respuesta a una palabra de la lista
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum WordMatch {
+    /// Igual salvo mayúsculas y tildes
+    Exact,
+    /// Con alguna errata tolerada
+    NearMiss,
+    None,
+}
+
+/// Puntuación de cada posición y respuestas aceptadas con erratas
+struct Recall {
+    scores: Vec<f32>,
+    near_misses: Vec<NearMiss>,
+}
+
+/// Compara las respuestas con la lista sin tener en cuenta mayúsculas ni
+/// tildes, y perdonando hasta `tolerance` erratas en las palabras largas
+struct Matcher<'a> {
+    original: &'a [String],
+    normalized: Vec<String>,
+    tolerance: usize,
+}
+
+impl<'a> Matcher<'a> {
+    fn new(original: &'a [String], tolerance: usize) -> Self {
+        Self {
+            original,
+            normalized: original.iter().map(|w| normalize(w)).collect(),
+            tolerance,
+        }
+    }
+
+    /// Erratas permitidas: ninguna en palabras de menos de 5 letras, y una
+    /// más por cada 5 letras hasta llegar a la tolerancia elegida
+    fn allowed_typos(&self, word: &str) -> usize {
+        self.tolerance.min(word.chars().count() / 5)
+    }
+
+    fn compare(&self, index: usize, answer: &str) -> WordMatch {
+        let target = &self.normalized[index];
+        let answer = normalize(answer);
+        if answer.is_empty() {
+            WordMatch::None
+        } else if answer == *target {
+            WordMatch::Exact
+        } else if distance(target, &answer) <= self.allowed_typos(target) {
+            WordMatch::NearMiss
+        } else {
+            WordMatch::None
+        }
+    }
+
+    fn near_miss(&self, index: usize, answer: &str) -> NearMiss {
+        NearMiss {
+            answer: answer.to_string(),
+            target: self.original[index].clone(),
+        }
+    }
+
+    /// Recuerdo libre: cada palabra de la lista cuenta una sola vez, aunque se
+    /// escriba repetida y en cualquier orden. Una errata solo se acepta si
+    /// ninguna respuesta coincide exactamente.
+    fn free_recall(&self, answers: &[String]) -> Recall {
+        let mut used = vec![false; answers.len()];
+        let mut recall = Recall { scores: vec![0.0; self.original.len()], near_misses: Vec::new() };
+
+        for index in 0..self.original.len() {
+            let exact = (0..answers.len()).find(|&j| !used[j] && self.compare(index, &answers[j]) == WordMatch::Exact);
+            if let Some(j) = exact {
+                used[j] = true;
+                recall.scores[index] = 1.0;
             }
         }
-        
-        if self.words_to_remember.is_empty() { 
-            0.0 
-        } else { 
-            correct as f32 / self.words_to_remember.len() as f32 
+        for index in 0..self.original.len() {
+            if recall.scores[index] > 0.0 {
+                continue;
+            }
+            let near = (0..answers.len()).find(|&j| !used[j] && self.compare(index, &answers[j]) == WordMatch::NearMiss);
+            if let Some(j) = near {
+                used[j] = true;
+                recall.scores[index] = 1.0;
+                recall.near_misses.push(self.near_miss(index, &answers[j]));
+            }
+        }
+        recall
+    }
+
+    /// Recuerdo en orden: cada palabra en su posición vale 1, y las dos palabras
+    /// de un par adyacente intercambiado valen 0,5 cada una
+    fn serial_recall(&self, answers: &[String]) -> Recall {
+        let matches = |index: usize, j: usize| {
+            answers.get(j).map_or(WordMatch::None, |a| self.compare(index, a))
+        };
+        let mut recall = Recall { scores: vec![0.0; self.original.len()], near_misses: Vec::new() };
+
+        for (index, answer) in answers.iter().enumerate().take(self.original.len()) {
+            let found = self.compare(index, answer);
+            if found != WordMatch::None {
+                recall.scores[index] = 1.0;
+                if found == WordMatch::NearMiss {
+                    recall.near_misses.push(self.near_miss(index, answer));
+                }
+            }
         }
+        for index in 1..self.original.len() {
+            let swapped = matches(index, index - 1) != WordMatch::None && matches(index - 1, index) != WordMatch::None;
+            if swapped && recall.scores[index - 1] == 0.0 && recall.scores[index] == 0.0 {
+                recall.scores[index - 1] = 0.5;
+                recall.scores[index] = 0.5;
+            }
+        }
+        recall
+    }
+
+    /// Respuestas que no se parecen a ninguna palabra de la lista
+    fn intrusions(&self, answers: &[String]) -> Vec<String> {
+        let mut intrusions: Vec<String> = Vec::new();
+        for answer in answers {
+            let in_list = (0..self.original.len()).any(|index| self.compare(index, answer) != WordMatch::None);
+            if answer != SKIP_MARK && !in_list && !intrusions.contains(answer) {
+                intrusions.push(answer.clone());
+            }
+        }
+        intrusions
     }
 }
 
@@ -128,6 +372,8 @@ impl Game for WordMemoryGame {
                     ui.label("1. Se mostrarán palabras una por una");
                     ui.label("2. Memoriza todas las palabras que veas");
                     ui.label("3. Después escribe todas las palabras que recuerdes");
+                    ui.label("4. En el modo «En orden» cada palabra debe ir en su posición");
+                    ui.label("5. En el modo «Parejas» verás dos palabras a la vez; después se mostrará la primera y escribirás la segunda");
                 });
                 
                 ui.add_space(20.0);
@@ -135,13 +381,89 @@ impl Game for WordMemoryGame {
                 ui.group(|ui| {
                     ui.label("⚙️ Configuración:");
                     ui.add_space(10.0);
-                    ui.label(format!("Palabras a memorizar: {}", self.words_to_remember.len()));
-                    ui.label(format!("Tiempo por palabra: {:.1}s", self.word_display_time.as_secs_f32()));
+                    
+                    let mut changed = false;
+                    ui.horizontal(|ui| {
+                        ui.label("Lista:");
+                        let selected = self.list_name().to_string();
+                        egui::ComboBox::from_id_source("word_list")
+                            .selected_text(&selected)
+                            .show_ui(ui, |ui| {
+                                for list in &self.library.lists {
+                                    let name = if list.name == BUILTIN_LIST { None } else { Some(list.name.clone()) };
+                                    changed |= ui.selectable_value(&mut self.config.word_list, name, &list.name).changed();
+                                }
+                            });
+                    });
+                    if let Some(list) = self.library.get(self.list_name()) {
+                        ui.label(egui::RichText::new(list.describe()).small());
+                    }
+                    
+                    ui.add_space(10.0);
+                    ui.horizontal(|ui| {
+                        ui.label("Dificultad:");
+                        for difficulty in [Difficulty::Easy, Difficulty::Medium, Difficulty::Hard] {
+                            let name = difficulty.name().to_string();
+                            changed |= ui.radio_value(&mut self.config.difficulty, difficulty, name).changed();
+                        }
+                    });
+                    if changed {
+                        self.generate_words();
+                    }
+                    
+                    ui.add_space(10.0);
+                    ui.horizontal(|ui| {
+                        ui.label("Recuerdo:");
+                        for mode in RecallMode::all() {
+                            let name = mode.name().to_string();
+                            if ui.radio_value(&mut self.config.recall_mode, mode, name).changed() {
+                                self.generate_words();
+                            }
+                        }
+                    });
+                    match self.config.recall_mode {
+                        RecallMode::Serial => {
+                            ui.label("Un intercambio de dos palabras seguidas cuenta medio punto para cada una.");
+                        }
+                        RecallMode::Pairs => {
+                            ui.label("Imagina una escena en la que las dos palabras interactúen: cuanto más absurda, mejor se recuerda.");
+                        }
+                        RecallMode::Free => {}
+                    }
+                    ui.horizontal(|ui| {
+                        ui.label("Erratas permitidas:");
+                        ui.add(egui::Slider::new(&mut self.config.typo_tolerance, 0..=2));
+                    });
+                    delayed_recall_selector(ui, "word_memory_delay", &mut self.config.delayed_recall);
+                    ui.label(egui::RichText::new(
+                        "Las mayúsculas y las tildes nunca cuentan. Las erratas solo se perdonan en palabras de 5 letras o más (dos, a partir de 10)."
+                    ).small());
+                    ui.add_space(10.0);
+                    if self.config.recall_mode == RecallMode::Pairs {
+                        ui.label(format!("Parejas a memorizar: {}", self.item_count()));
+                        ui.label(format!("Tiempo por pareja: {:.1}s", self.word_display_time.as_secs_f32()));
+                    } else {
+                        ui.label(format!("Palabras a memorizar: {}", self.words_to_remember.len()));
+                        ui.label(format!("Tiempo por palabra: {:.1}s", self.word_display_time.as_secs_f32()));
+                    }
                 });
                 
+                if !self.library.errors.is_empty() {
+                    ui.add_space(10.0);
+                    ui.collapsing(format!("⚠️ {} problema(s) al cargar listas", self.library.errors.len()), |ui| {
+                        for error in &self.library.errors {
+                            ui.colored_label(egui::Color32::from_rgb(200, 120, 0), error);
+                        }
+                    });
+                }
+                ui.label(egui::RichText::new(format!(
+                    "Añade tus listas como .txt en {}",
+                    GameStorage::word_lists_dir().display()
+                )).small());
+                
                 ui.add_space(20.0);
                 
-                if ui.button("Comenzar").clicked() {
+                if ui.add_enabled(self.item_count() > 0, egui::Button::new("Comenzar")).clicked() {
                     self.state = MemoryState::ShowingWords;
                     self.start_time = Some(Instant::now());
                     self.last_word_time = Some(Instant::now());
@@ -152,10 +474,14 @@ impl Game for WordMemoryGame {
                 if let Some(word_start) = self.last_word_time {
                     if word_start.elapsed() >= self.word_display_time {
                         self.current_word_index += 1;
-                        if self.current_word_index >= self.words_to_remember.len() {
-                            self.state = MemoryState::Recall;
+                        if self.current_word_index >= self.item_count() {
+                            if self.config.recall_mode == RecallMode::Pairs {
+                                self.start_cued_recall();
+                            } else {
+                                self.state = MemoryState::Recall;
+                                self.focus_input = true;
+                            }
                             self.start_time = Some(Instant::now());
-                            self.focus_input = true;
                             return;
                         }
                         self.last_word_time = Some(Instant::now());
@@ -165,14 +491,16 @@ impl Game for WordMemoryGame {
                 ui.vertical_centered(|ui| {
                     ui.add_space(100.0);
                     
-                    ui.label(format!("Palabra {} de {}", 
+                    let item = if self.config.recall_mode == RecallMode::Pairs { "Pareja" } else { "Palabra" };
+                    ui.label(format!("{} {} de {}", 
+                        item,
                         self.current_word_index + 1, 
-                        self.words_to_remember.len()));
+                        self.item_count()));
                     
                     ui.add_space(20.0);
                     
-                    if self.current_word_index < self.words_to_remember.len() {
-                        ui.label(egui::RichText::new(&self.words_to_remember[self.current_word_index])
+                    if self.current_word_index < self.item_count() {
+                        ui.label(egui::RichText::new(self.item_text(self.current_word_index))
                             .size(36.0)
                             .strong());
                     }
@@ -191,7 +519,13 @@ impl Game for WordMemoryGame {
                 ui.heading("Escribe las palabras que recuerdas:");
                 ui.separator();
                 
-                ui.label("Escribe todas las palabras separadas por espacios:");
+                match self.config.recall_mode {
+                    RecallMode::Free => ui.label("Escribe todas las palabras separadas por espacios:"),
+                    RecallMode::Serial | RecallMode::Pairs => ui.label(format!(
+                        "Escribe las palabras en el orden en que aparecieron. Usa «{}» para una que no recuerdes:",
+                        SKIP_MARK
+                    )),
+                };
                 
                 let text_edit = egui::TextEdit::multiline(&mut self.user_input)
                     .desired_rows(8)
@@ -214,25 +548,46 @@ impl Game for WordMemoryGame {
                 ui.add_space(10.0);
                 
                 if button_with_enter(ui, "Terminar") {
-                    // Calcular palabras correctas para el resumen
-                    let user_words: Vec<String> = self.user_input
-                        .split_whitespace()
-                        .map(|s| s.to_lowercase())
-                        .collect();
-                    
-                    let original_words: Vec<String> = self.words_to_remember
-                        .iter()
-                        .map(|s| s.to_lowercase())
-                        .collect();
-                    
-                    self.matched_words = user_words.iter()
-                        .filter(|w| original_words.contains(w))
-                        .cloned()
-                        .collect();
-                    
+                    self.score_answers();
                     self.finished = true;
                 }
             }
+            
+            MemoryState::CuedRecall => {
+                ui.horizontal(|ui| {
+                    if ui.button("< Menú").clicked() {
+                        self.should_go_to_menu = true;
+                    }
+                });
+                ui.separator();
+                
+                let done = self.pair_trials.len();
+                let Some(&pair) = self.pair_order.get(done) else {
+                    return;
+                };
+                
+                ui.vertical_centered(|ui| {
+                    ui.add_space(60.0);
+                    ui.label(format!("Pareja {} de {}", done + 1, self.pair_order.len()));
+                    ui.add_space(20.0);
+                    ui.label(egui::RichText::new(format!("{} — ?", self.words_to_remember[2 * pair]))
+                        .size(36.0)
+                        .strong());
+                    ui.add_space(20.0);
+                    ui.label("¿Qué palabra la acompañaba?");
+                    
+                    let response = ui.add(egui::TextEdit::singleline(&mut self.user_input).desired_width(250.0));
+                    if self.focus_input {
+                        response.request_focus();
+                        self.focus_input = false;
+                    }
+                    
+                    ui.add_space(10.0);
+                    if button_with_enter(ui, "Siguiente") {
+                        self.submit_pair_answer();
+                    }
+                });
+            }
         }
     }
 
@@ -251,8 +606,25 @@ impl Game for WordMemoryGame {
             return None;
         }
         
+        if let Some(pending) = &self.delayed_test {
+            let (stimulus, answers, item_scores) = self.scored_items();
+            return Some(pending.to_result(stimulus, answers, item_scores));
+        }
+        
+        if self.config.recall_mode == RecallMode::Pairs {
+            return Some(GameResult {
+                game_type: crate::GameType::WordMemory,
+                score: self.calculate_accuracy() * 100.0,
+                details: GameDetails::PairedAssociates {
+                    pairs: self.pair_trials.clone(),
+                    list_name: self.list_name().to_string(),
+                },
+                timestamp: std::time::SystemTime::now(),
+            });
+        }
+        
         let accuracy = self.calculate_accuracy();
-        let words_correct = self.matched_words.len();
+        let words_correct = self.position_scores.iter().filter(|&&s| s >= 1.0).count();
         
         Some(GameResult {
             game_type: crate::GameType::WordMemory,
@@ -260,6 +632,12 @@ impl Game for WordMemoryGame {
             details: GameDetails::WordMemory {
                 words_correct,
                 original_words: self.words_to_remember.clone(),
+                recall_mode: self.config.recall_mode,
+                answers: self.answer_words(),
+                position_scores: self.position_scores.clone(),
+                list_name: self.list_name().to_string(),
+                near_misses: self.near_misses.clone(),
+                intrusions: self.intrusions.clone(),
             },
             timestamp: std::time::SystemTime::now(),
         })
@@ -268,4 +646,28 @@ impl Game for WordMemoryGame {
     fn needs_repaint(&self) -> bool {
         matches!(self.state, MemoryState::ShowingWords) && !self.finished && !self.should_go_to_menu
     }
+
+    fn get_config(&self) -> Option<&GameConfig> {
+        // La prueba diferida usa el modo de la partida original, no el elegido
+        self.delayed_test.is_none().then_some(&self.config)
+    }
+
+    fn save_to_storage(&mut self, storage: &mut GameStorage) {
+        if !self.finished {
+            return;
+        }
+        match (&self.delayed_test, self.config.delayed_recall) {
+            (Some(pending), _) => storage.complete_recall(pending.studied_at),
+            (None, Some(delay)) => storage.schedule_recall(PendingRecall {
+                game_type: crate::GameType::WordMemory,
+                delay,
+                studied_at: std::time::SystemTime::now(),
+                immediate_score: self.calculate_accuracy(),
+                stimulus: self.words_to_remember.clone(),
+                recall_mode: self.config.recall_mode,
+                number_format: NumberFormat::default(),
+            }),
+            (None, None) => {}
+        }
+    }
 }
\ No newline at end of file
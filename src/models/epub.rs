@@ -0,0 +1,252 @@
+//! Lectura de libros EPUB
+//!
+//! Un EPUB es un zip con un índice (OPF) que enumera los capítulos en orden
+//! de lectura. Solo se extrae el texto; imágenes y estilos se ignoran.
+
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+use zip::ZipArchive;
+
+/// Capítulos con menos palabras (portada, índice...) se descartan
+const MIN_CHAPTER_WORDS: usize = 50;
+
+/// Capítulo de un libro ya convertido a texto plano
+#[derive(Debug, Clone)]
+pub struct Chapter {
+    pub title: String,
+    pub text: String,
+}
+
+/// Libro abierto: título y capítulos en orden de lectura
+#[derive(Debug, Clone)]
+pub struct Book {
+    pub title: String,
+    pub chapters: Vec<Chapter>,
+}
+
+impl Book {
+    pub fn open_epub(path: &Path) -> Result<Self, String> {
+        let file = File::open(path).map_err(|e| e.to_string())?;
+        let mut archive = ZipArchive::new(file).map_err(|_| "no es un EPUB válido".to_string())?;
+
+        let container = read_entry(&mut archive, "META-INF/container.xml")?;
+        let opf_path = find_tag(&container, "rootfile")
+            .and_then(|tag| attribute(tag, "full-path"))
+            .ok_or("falta el índice del libro (OPF)")?;
+        let opf = read_entry(&mut archive, &opf_path)?;
+        let base = opf_path.rsplit_once('/').map(|(dir, _)| format!("{}/", dir)).unwrap_or_default();
+
+        let title = element_text(&opf, "dc:title").unwrap_or_else(|| {
+            path.file_stem().unwrap_or_default().to_string_lossy().to_string()
+        });
+
+        // Manifiesto: id -> archivo
+        let manifest: Vec<(String, String)> = tags(&opf, "item")
+            .into_iter()
+            .filter_map(|tag| Some((attribute(tag, "id")?, attribute(tag, "href")?)))
+            .collect();
+
+        let mut chapters = Vec::new();
+        for tag in tags(&opf, "itemref") {
+            let Some(idref) = attribute(tag, "idref") else {
+                continue;
+            };
+            let Some((_, href)) = manifest.iter().find(|(id, _)| *id == idref) else {
+                continue;
+            };
+            let entry = format!("{}{}", base, percent_decode(href.split('#').next().unwrap_or_default()));
+            let Ok(html) = read_entry(&mut archive, &entry) else {
+                continue;
+            };
+
+            let text = html_to_text(&html);
+            if text.split_whitespace().count() < MIN_CHAPTER_WORDS {
+                continue;
+            }
+            let title = ["h1", "h2", "h3", "title"]
+                .iter()
+                .find_map(|name| element_text(&html, name))
+                .map(|t| strip_tags(&t))
+                .filter(|t| !t.is_empty())
+                .unwrap_or_else(|| format!("Capítulo {}", chapters.len() + 1));
+            chapters.push(Chapter { title, text });
+        }
+
+        if chapters.is_empty() {
+            return Err("el libro no tiene capítulos con texto".to_string());
+        }
+        Ok(Self { title, chapters })
+    }
+}
+
+fn read_entry(archive: &mut ZipArchive<File>, name: &str) -> Result<String, String> {
+    let mut entry = archive.by_name(name).map_err(|_| format!("falta {} en el EPUB", name))?;
+    let mut content = String::new();
+    entry.read_to_string(&mut content).map_err(|e| e.to_string())?;
+    Ok(content)
+}
+
+/// Etiquetas de apertura `<name ...>` del documento
+fn tags<'a>(xml: &'a str, name: &str) -> Vec<&'a str> {
+    let open = format!("<{}", name);
+    xml.match_indices(open.as_str())
+        .filter_map(|(start, _)| {
+            let rest = &xml[start + open.len()..];
+            // Evita que "item" encuentre también "itemref"
+            if !rest.starts_with(|c: char| c.is_whitespace() || c == '>' || c == '/') {
+                return None;
+            }
+            let end = rest.find('>')?;
+            Some(&xml[start..start + open.len() + end + 1])
+        })
+        .collect()
+}
+
+fn find_tag<'a>(xml: &'a str, name: &str) -> Option<&'a str> {
+    tags(xml, name).into_iter().next()
+}
+
+fn attribute(tag: &str, name: &str) -> Option<String> {
+    let key = format!(" {}=", name);
+    let start = tag.find(&key)? + key.len();
+    let quote = tag[start..].chars().next()?;
+    let value = &tag[start + 1..];
+    let end = value.find(quote)?;
+    Some(decode_entities(&value[..end]))
+}
+
+/// Contenido de la primera etiqueta `<name>...</name>`
+fn element_text(xml: &str, name: &str) -> Option<String> {
+    let tag = find_tag(xml, name)?;
+    let start = xml.find(tag)? + tag.len();
+    let end = xml[start..].find(&format!("</{}>", name))?;
+    let text = decode_entities(xml[start..start + end].trim());
+    (!text.is_empty()).then_some(text)
+}
+
+/// Texto de un fragmento en una sola línea, sin etiquetas
+fn strip_tags(html: &str) -> String {
+    let mut text = String::new();
+    let mut inside = false;
+    for c in html.chars() {
+        match c {
+            '<' => inside = true,
+            '>' => {
+                inside = false;
+                text.push(' ');
+            }
+            _ if !inside => text.push(c),
+            _ => {}
+        }
+    }
+    decode_entities(&text).split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Quita las etiquetas HTML dejando una línea en blanco entre bloques
+fn html_to_text(html: &str) -> String {
+    let body = match html.find("<body") {
+        Some(start) => &html[start..],
+        None => html,
+    };
+
+    let mut text = String::new();
+    let mut rest = body;
+    while let Some(open) = rest.find('<') {
+        text.push_str(&rest[..open]);
+        let Some(close) = rest[open..].find('>').map(|i| open + i) else {
+            rest = "";
+            break;
+        };
+        let tag = rest[open + 1..close].trim_start_matches('/').to_lowercase();
+        let name = tag.split(|c: char| c.is_whitespace() || c == '/').next().unwrap_or_default();
+
+        // Scripts y estilos no son texto del libro, y los títulos ya se
+        // guardan aparte como título del capítulo
+        if matches!(name, "script" | "style" | "h1" | "h2" | "h3" | "h4" | "h5" | "h6") && !rest[open + 1..].starts_with('/') {
+            let end_tag = format!("</{}>", name);
+            rest = rest[close..].find(&end_tag).map(|i| &rest[close + i + end_tag.len()..]).unwrap_or("");
+            continue;
+        }
+        if matches!(name, "p" | "div" | "br" | "li" | "blockquote" | "tr") {
+            text.push_str("\n\n");
+        } else {
+            text.push(' ');
+        }
+        rest = &rest[close + 1..];
+    }
+    text.push_str(rest);
+
+    // Une las líneas de cada párrafo y deja una línea en blanco entre ellos
+    decode_entities(&text)
+        .split("\n\n")
+        .map(|p| p.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|p| !p.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+fn decode_entities(text: &str) -> String {
+    let mut out = String::new();
+    let mut rest = text;
+    while let Some(amp) = rest.find('&') {
+        out.push_str(&rest[..amp]);
+        let after = &rest[amp + 1..];
+        let decoded = after.find(';').filter(|&end| end <= 10).and_then(|end| {
+            let entity = &after[..end];
+            let c = match entity {
+                "amp" => Some('&'),
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                "nbsp" => Some(' '),
+                "laquo" => Some('«'),
+                "raquo" => Some('»'),
+                "mdash" => Some('—'),
+                "ndash" => Some('–'),
+                "hellip" => Some('…'),
+                _ => {
+                    let code = if let Some(hex) = entity.strip_prefix("#x").or_else(|| entity.strip_prefix("#X")) {
+                        u32::from_str_radix(hex, 16).ok()
+                    } else {
+                        entity.strip_prefix('#').and_then(|dec| dec.parse().ok())
+                    };
+                    code.and_then(char::from_u32)
+                }
+            };
+            c.map(|c| (c, end))
+        });
+        match decoded {
+            Some((c, end)) => {
+                out.push(c);
+                rest = &after[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = after;
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+fn percent_decode(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() {
+            let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).unwrap_or_default();
+            if let Ok(byte) = u8::from_str_radix(hex, 16) {
+                out.push(byte);
+                i += 3;
+                continue;
+            }
+        }
+        out.push(bytes[i]);
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).to_string()
+}
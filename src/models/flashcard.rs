@@ -0,0 +1,368 @@
+//! Tarjetas de repaso espaciado
+//!
+//! Cada tarjeta guarda su facilidad, intervalo y próxima fecha de repaso
+//! según el algoritmo SM-2. Las tarjetas se importan de archivos CSV o TSV
+//! (como los que exporta Anki) o se crean a partir de la lista del Sistema Mayor.
+
+use chrono::{Local, TimeZone};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+
+/// Mazo de las tarjetas creadas con la lista del Sistema Mayor
+pub const PEG_DECK: &str = "Sistema Mayor";
+
+/// Nombres habituales de la primera columna en una fila de cabecera
+const HEADER_NAMES: &[&str] = &["front", "anverso", "question", "pregunta", "palabra", "word"];
+
+const START_EASE: f32 = 2.5;
+const MIN_EASE: f32 = 1.3;
+const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Valoración del recuerdo al ver el reverso
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Grade {
+    Again,
+    Hard,
+    Good,
+    Easy,
+}
+
+impl Grade {
+    pub fn all() -> [Grade; 4] {
+        [Grade::Again, Grade::Hard, Grade::Good, Grade::Easy]
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            Grade::Again => "Otra vez",
+            Grade::Hard => "Difícil",
+            Grade::Good => "Bien",
+            Grade::Easy => "Fácil",
+        }
+    }
+
+    /// Calidad de la respuesta en la escala de SM-2 (0 a 5)
+    fn quality(&self) -> f32 {
+        match self {
+            Grade::Again => 1.0,
+            Grade::Hard => 3.0,
+            Grade::Good => 4.0,
+            Grade::Easy => 5.0,
+        }
+    }
+}
+
+/// Repaso de una tarjeta
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CardReview {
+    pub timestamp: SystemTime,
+    pub grade: Grade,
+    /// Días hasta el siguiente repaso que dio esta nota
+    pub interval_days: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Card {
+    pub front: String,
+    pub back: String,
+    pub deck: String,
+    /// Factor de facilidad de SM-2: cuánto crece el intervalo tras cada acierto
+    pub ease: f32,
+    pub interval_days: u32,
+    /// Aciertos seguidos desde el último fallo
+    pub repetitions: u32,
+    pub due: SystemTime,
+    #[serde(default)]
+    pub reviews: Vec<CardReview>,
+}
+
+impl Card {
+    pub fn new(front: &str, back: &str, deck: &str) -> Self {
+        Self {
+            front: front.to_string(),
+            back: back.to_string(),
+            deck: deck.to_string(),
+            ease: START_EASE,
+            interval_days: 0,
+            repetitions: 0,
+            due: SystemTime::now(),
+            reviews: Vec::new(),
+        }
+    }
+
+    pub fn is_new(&self) -> bool {
+        self.reviews.is_empty()
+    }
+
+    /// Tarjeta ya estudiada que toca repasar antes de `by`
+    pub fn is_due(&self, by: SystemTime) -> bool {
+        !self.is_new() && self.due <= by
+    }
+
+    /// Días hasta el siguiente repaso si se valora con `grade`
+    pub fn next_interval(&self, grade: Grade) -> u32 {
+        if grade == Grade::Again {
+            return 1;
+        }
+        match self.repetitions {
+            0 => 1,
+            1 => 6,
+            _ => (self.interval_days as f32 * self.next_ease(grade)).round().max(1.0) as u32,
+        }
+    }
+
+    fn next_ease(&self, grade: Grade) -> f32 {
+        let miss = 5.0 - grade.quality();
+        (self.ease + 0.1 - miss * (0.08 + miss * 0.02)).max(MIN_EASE)
+    }
+
+    /// Aplica SM-2: un fallo reinicia los aciertos seguidos sin tocar la
+    /// facilidad; un acierto alarga el intervalo y ajusta la facilidad.
+    pub fn review(&mut self, grade: Grade, now: SystemTime) {
+        self.interval_days = self.next_interval(grade);
+        if grade == Grade::Again {
+            self.repetitions = 0;
+        } else {
+            self.ease = self.next_ease(grade);
+            self.repetitions += 1;
+        }
+        self.due = now + DAY * self.interval_days;
+        self.reviews.push(CardReview {
+            timestamp: now,
+            grade,
+            interval_days: self.interval_days,
+        });
+    }
+}
+
+/// Último instante del día de hoy (hora local): lo que vence antes toca hoy
+pub fn end_of_today() -> SystemTime {
+    Local::now()
+        .date_naive()
+        .and_hms_opt(23, 59, 59)
+        .and_then(|end| Local.from_local_datetime(&end).earliest())
+        .map(SystemTime::from)
+        .unwrap_or_else(SystemTime::now)
+}
+
+/// Añade a `cards` las tarjetas nuevas que no estén ya (mismo mazo y anverso).
+/// Devuelve cuántas se añadieron.
+pub fn merge_cards(cards: &mut Vec<Card>, new_cards: Vec<Card>) -> usize {
+    let mut added = 0;
+    for card in new_cards {
+        if !cards.iter().any(|c| c.deck == card.deck && c.front == card.front) {
+            cards.push(card);
+            added += 1;
+        }
+    }
+    added
+}
+
+/// Tarjetas número → palabra de la lista del Sistema Mayor
+pub fn peg_cards(pegs: &BTreeMap<u8, String>) -> Vec<Card> {
+    pegs.iter()
+        .map(|(number, word)| Card::new(&format!("{:02}", number), word, PEG_DECK))
+        .collect()
+}
+
+/// Lee tarjetas de un archivo CSV o TSV: anverso, reverso y, opcionalmente,
+/// el mazo. Sin columna de mazo se usa el nombre del archivo. Se respetan las
+/// cabeceras `#separator:` y `#html:` de las exportaciones de Anki, y se salta
+/// una primera fila de títulos como `front,back`.
+pub fn import_cards(path: &Path) -> Result<Vec<Card>, String> {
+    let content = fs::read_to_string(path).map_err(|e| e.to_string())?;
+    let file_deck = path.file_stem().unwrap_or_default().to_string_lossy().to_string();
+    let is_csv = path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("csv"));
+
+    // Las cabeceras de Anki van al principio del archivo
+    let mut separator = if is_csv { ',' } else { '\t' };
+    let mut html = false;
+    for header in content.lines().map_while(|line| line.strip_prefix('#')) {
+        match header.split_once(':') {
+            Some(("separator", value)) => {
+                separator = match value.trim().to_lowercase().as_str() {
+                    "comma" | "," => ',',
+                    "semicolon" | ";" => ';',
+                    "pipe" | "|" => '|',
+                    "space" => ' ',
+                    _ => '\t',
+                };
+            }
+            Some(("html", value)) => html = value.trim() == "true",
+            _ => {}
+        }
+    }
+
+    let mut cards = Vec::new();
+    for (n, record) in parse_records(&content, separator).into_iter().enumerate() {
+        let mut fields = record.into_iter().map(|f| {
+            let f = if html { strip_html(&f) } else { f };
+            f.trim().to_string()
+        });
+        let (Some(front), Some(back)) = (fields.next(), fields.next()) else {
+            continue;
+        };
+        if front.is_empty() || back.is_empty() {
+            continue;
+        }
+        if n == 0 && HEADER_NAMES.contains(&front.to_lowercase().as_str()) {
+            continue;
+        }
+        let deck = fields.next().filter(|d| !d.is_empty()).unwrap_or_else(|| file_deck.clone());
+        cards.push(Card::new(&front, &back, &deck));
+    }
+
+    if cards.is_empty() {
+        return Err("no hay tarjetas (cada línea necesita anverso y reverso)".to_string());
+    }
+    Ok(cards)
+}
+
+/// Separa el archivo en filas y campos. Un campo entre comillas puede
+/// contener el separador y saltos de línea, y `""` dentro de él es una
+/// comilla. Las líneas que empiezan por `#` y las vacías se ignoran.
+fn parse_records(content: &str, separator: char) -> Vec<Vec<String>> {
+    let mut records = Vec::new();
+    let mut record: Vec<String> = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut at_line_start = true;
+    let mut chars = content.chars().peekable();
+    while let Some(c) = chars.next() {
+        if at_line_start && c == '#' {
+            while chars.next_if(|&c| c != '\n').is_some() {}
+            continue;
+        }
+        at_line_start = false;
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' if quoted => quoted = false,
+            '"' if field.trim().is_empty() => {
+                field.clear();
+                quoted = true;
+            }
+            '\n' if !quoted => {
+                record.push(std::mem::take(&mut field));
+                if record.iter().any(|f| !f.trim().is_empty()) {
+                    records.push(std::mem::take(&mut record));
+                }
+                record.clear();
+                at_line_start = true;
+            }
+            '\r' if !quoted => {}
+            c if c == separator && !quoted => record.push(std::mem::take(&mut field)),
+            c => field.push(c),
+        }
+    }
+    record.push(field);
+    if record.iter().any(|f| !f.trim().is_empty()) {
+        records.push(record);
+    }
+    records
+}
+
+/// Quita las etiquetas HTML de un campo; los saltos de línea pasan a espacios
+fn strip_html(text: &str) -> String {
+    let mut out = String::new();
+    let mut inside = false;
+    for c in text.chars() {
+        match c {
+            '<' => inside = true,
+            '>' if inside => {
+                inside = false;
+                out.push(' ');
+            }
+            _ if !inside => out.push(c),
+            _ => {}
+        }
+    }
+    out.replace("&nbsp;", " ")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sm2_intervals_grow_after_each_success() {
+        let now = SystemTime::now();
+        let mut card = Card::new("a", "b", "mazo");
+        card.review(Grade::Good, now);
+        assert_eq!(card.interval_days, 1);
+        card.review(Grade::Good, now);
+        assert_eq!(card.interval_days, 6);
+        card.review(Grade::Good, now);
+        assert_eq!(card.interval_days, 15);
+        assert_eq!(card.due, now + DAY * 15);
+    }
+
+    #[test]
+    fn sm2_again_resets_repetitions_but_keeps_ease() {
+        let now = SystemTime::now();
+        let mut card = Card::new("a", "b", "mazo");
+        card.review(Grade::Easy, now);
+        card.review(Grade::Easy, now);
+        let ease = card.ease;
+        card.review(Grade::Again, now);
+        assert_eq!(card.interval_days, 1);
+        assert_eq!(card.repetitions, 0);
+        assert_eq!(card.ease, ease);
+        card.review(Grade::Good, now);
+        assert_eq!(card.interval_days, 1);
+    }
+
+    #[test]
+    fn sm2_ease_never_drops_below_floor() {
+        let now = SystemTime::now();
+        let mut card = Card::new("a", "b", "mazo");
+        for _ in 0..20 {
+            card.review(Grade::Hard, now);
+        }
+        assert_eq!(card.ease, MIN_EASE);
+    }
+
+    #[test]
+    fn quoted_fields_keep_separators_quotes_and_newlines() {
+        let records = parse_records("\"uno, dos\",\"di \"\"hola\"\"\"\n\"línea 1\nlínea 2\",b\r\n", ',');
+        assert_eq!(
+            records,
+            vec![
+                vec!["uno, dos".to_string(), "di \"hola\"".to_string()],
+                vec!["línea 1\nlínea 2".to_string(), "b".to_string()],
+            ]
+        );
+    }
+
+    #[test]
+    fn comment_lines_and_blank_lines_are_skipped() {
+        let records = parse_records("#separator:tab\n\na\tb\n# nota\nc\td\n", '\t');
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[1], vec!["c".to_string(), "d".to_string()]);
+    }
+
+    #[test]
+    fn import_skips_header_after_anki_headers() {
+        let path = std::env::temp_dir().join(format!("flashcard_test_{}.csv", std::process::id()));
+        fs::write(&path, "#separator:semicolon\n#html:true\nfront;back\n\"<b>perro</b>\";\"dog\nanimal\";Inglés\n").unwrap();
+        let cards = import_cards(&path);
+        fs::remove_file(&path).unwrap();
+
+        let cards = cards.unwrap();
+        assert_eq!(cards.len(), 1);
+        assert_eq!(cards[0].front, "perro");
+        assert_eq!(cards[0].back, "dog animal");
+        assert_eq!(cards[0].deck, "Inglés");
+    }
+}
@@ -2,7 +2,7 @@
 
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
-use super::Difficulty;
+use super::{Difficulty, NumberFormat, PresentationMode, ReadingMode, RecallDelay, RecallMode, SchulteSymbols};
 
 /// Configuración compartida para todos los juegos
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -13,6 +13,88 @@ pub struct GameConfig {
     /// Whether after showing the numbers the game should present input boxes to fill
     /// (used by the iNumbs / retentiva training).
     pub fill_boxes: bool,
+    /// Si iNumbs muestra toda la cuadrícula a la vez o cada número por turnos.
+    #[serde(default)]
+    pub presentation: PresentationMode,
+    /// Pausa en blanco entre números en la presentación secuencial.
+    #[serde(default = "default_inter_stimulus_gap")]
+    pub inter_stimulus_gap: Duration,
+    /// Tipo de números que iNumbs pide memorizar (pares, binario, hexadecimal...).
+    #[serde(default)]
+    pub number_format: NumberFormat,
+    /// Cómo presenta el texto Comprensión de Texto.
+    #[serde(default)]
+    pub reading_mode: ReadingMode,
+    /// Palabras por minuto objetivo en los modos de lectura guiada.
+    #[serde(default = "default_target_wpm")]
+    pub target_wpm: u32,
+    /// Palabras que se muestran a la vez en la lectura RSVP.
+    #[serde(default = "default_rsvp_chunk_size")]
+    pub rsvp_chunk_size: usize,
+    /// Palabras por minuto que acelera el marcapasos por cada minuto de lectura.
+    #[serde(default = "default_pacer_ramp")]
+    pub pacer_ramp_wpm: u32,
+    /// Filas y columnas de la tabla de Schulte (de 3 a 7).
+    #[serde(default = "default_schulte_size")]
+    pub schulte_size: usize,
+    #[serde(default)]
+    pub schulte_symbols: SchulteSymbols,
+    /// Si Memoria de Palabras puntúa las palabras en cualquier orden, por posición o como pares con pista.
+    #[serde(default)]
+    pub recall_mode: RecallMode,
+    /// Lista de la que Memoria de Palabras toma las palabras (`None` para la incluida).
+    #[serde(default)]
+    pub word_list: Option<String>,
+    /// Prueba de recuerdo diferido que se programa tras Memoria de Palabras e iNumbs, si la hay.
+    #[serde(default)]
+    pub delayed_recall: Option<RecallDelay>,
+    /// Mazo de tarjetas que se repasa (`None` para todos).
+    #[serde(default)]
+    pub flashcard_deck: Option<String>,
+    /// Máximo de tarjetas nuevas por sesión.
+    #[serde(default = "default_new_cards")]
+    pub new_cards_per_session: usize,
+    /// Erratas que se perdonan en las palabras largas de Memoria de Palabras (0 exige la ortografía exacta).
+    #[serde(default = "default_typo_tolerance")]
+    pub typo_tolerance: usize,
+    /// Archivo de texto que se está leyendo por partes en Comprensión de Texto, si lo hay.
+    #[serde(default)]
+    pub source_path: Option<String>,
+    /// Palabras aproximadas por parte al importar un texto sin capítulos.
+    #[serde(default = "default_import_passage_words")]
+    pub import_passage_words: usize,
+}
+
+fn default_new_cards() -> usize {
+    20
+}
+
+fn default_typo_tolerance() -> usize {
+    1
+}
+
+fn default_import_passage_words() -> usize {
+    super::text_import::DEFAULT_PASSAGE_WORDS
+}
+
+fn default_schulte_size() -> usize {
+    5
+}
+
+fn default_pacer_ramp() -> u32 {
+    20
+}
+
+fn default_target_wpm() -> u32 {
+    300
+}
+
+fn default_rsvp_chunk_size() -> usize {
+    1
+}
+
+fn default_inter_stimulus_gap() -> Duration {
+    Duration::from_millis(500)
 }
 
 impl Default for GameConfig {
@@ -22,6 +104,23 @@ impl Default for GameConfig {
             duration: Duration::from_secs(30),
             word_count: 100,
             fill_boxes: false,
+            presentation: PresentationMode::Grid,
+            inter_stimulus_gap: default_inter_stimulus_gap(),
+            number_format: NumberFormat::Pairs,
+            reading_mode: ReadingMode::Normal,
+            target_wpm: default_target_wpm(),
+            rsvp_chunk_size: default_rsvp_chunk_size(),
+            pacer_ramp_wpm: default_pacer_ramp(),
+            schulte_size: default_schulte_size(),
+            schulte_symbols: SchulteSymbols::Numbers,
+            recall_mode: RecallMode::Free,
+            word_list: None,
+            typo_tolerance: default_typo_tolerance(),
+            delayed_recall: None,
+            flashcard_deck: None,
+            new_cards_per_session: default_new_cards(),
+            source_path: None,
+            import_passage_words: default_import_passage_words(),
         }
     }
 }
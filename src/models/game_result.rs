@@ -2,7 +2,8 @@
 
 use serde::{Deserialize, Serialize};
 use std::time::{Duration, SystemTime};
-use super::GameType;
+use super::{ChunkGrouping, DigitProgression, GameType, NumberFormat, PegDirection, PresentationMode, ReadingMode, RecallDelay, RecallMode, RecallOrder, SchulteSymbols, FlashStimulus, Grade};
+use crate::utils::edit_distance::EditErrors;
 
 /// Resultado de una partida
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -20,18 +21,241 @@ pub enum GameDetails {
         words_correct: usize, 
         total_words: usize,
         time_taken: Duration,
+        /// Desglose ronda a ronda (vacío en partidas guardadas antes de existir)
+        #[serde(default)]
+        rounds: Vec<NumberRound>,
+        /// Errores acumulados de todas las rondas
+        #[serde(default)]
+        errors: EditErrors,
+        #[serde(default)]
+        progression: DigitProgression,
+        /// Amplitud de dígitos estimada por la escalera (solo en modo adaptativo)
+        #[serde(default)]
+        span_estimate: Option<f32>,
+        #[serde(default)]
+        order: RecallOrder,
+        /// Agrupación con la que se mostraron los números
+        #[serde(default)]
+        chunking: ChunkGrouping,
     },
     WordMemory { 
         words_correct: usize, 
         original_words: Vec<String>,
+        #[serde(default)]
+        recall_mode: RecallMode,
+        /// Palabras escritas por el usuario, en el orden en que las escribió
+        #[serde(default)]
+        answers: Vec<String>,
+        /// Crédito (0 a 1) de cada posición de la lista: la curva de posición serial
+        #[serde(default)]
+        position_scores: Vec<f32>,
+        /// Lista de la que salieron las palabras
+        #[serde(default)]
+        list_name: String,
+        /// Palabras aceptadas pese a una errata
+        #[serde(default)]
+        near_misses: Vec<NearMiss>,
+        /// Palabras escritas que no estaban en la lista
+        #[serde(default)]
+        intrusions: Vec<String>,
+    },
+    /// Memoria de Palabras por parejas: se da la primera palabra y se pide la segunda
+    PairedAssociates {
+        pairs: Vec<PairTrial>,
+        /// Lista de la que salieron las palabras
+        #[serde(default)]
+        list_name: String,
     },
     TextComprehension { 
         questions_correct: usize, 
         total_questions: usize,
+        /// Identificador del texto leído
+        #[serde(default)]
+        passage_id: String,
+        #[serde(default)]
+        reading_time: Duration,
+        /// Palabras por minuto durante la lectura
+        #[serde(default)]
+        wpm: f32,
+        /// Velocidad efectiva: palabras por minuto × porcentaje de comprensión
+        #[serde(default)]
+        effective_wpm: f32,
+        #[serde(default)]
+        reading_mode: ReadingMode,
+        /// Velocidad de la guía (RSVP o marcador) al terminar de leer
+        #[serde(default)]
+        guide_wpm: Option<f32>,
     },
     INumbs {
         correct: usize,
         total: usize,
         time_taken: Duration,
+        #[serde(default)]
+        presentation: PresentationMode,
+        /// Resultado de cada casillero, en el orden mostrado (vacío sin rellenar casilleros)
+        #[serde(default)]
+        boxes: Vec<BoxResult>,
+        #[serde(default)]
+        number_format: NumberFormat,
+    },
+    MajorSystem {
+        responses: Vec<PegResponse>,
+    },
+    SchulteTable {
+        grid_size: usize,
+        symbols: SchulteSymbols,
+        time_taken: Duration,
+        errors: usize,
+        /// Tiempo entre cada acierto y el anterior, en el orden de la tabla
+        click_latencies: Vec<Duration>,
     },
+    Tachistoscope {
+        stimulus: FlashStimulus,
+        trials: Vec<FlashTrial>,
+        /// Mayor anchura leída correctamente
+        max_width: usize,
+    },
+    Flashcards {
+        reviews: Vec<FlashcardReview>,
+    },
+    /// Prueba de recuerdo diferido de una partida anterior (Memoria de Palabras o iNumbs)
+    DelayedRecall {
+        delay: RecallDelay,
+        studied_at: SystemTime,
+        /// Tiempo real entre el estudio y la prueba
+        elapsed: Duration,
+        /// Acierto (0 a 1) al terminar la partida original
+        immediate_score: f32,
+        /// Elementos estudiados y respuesta y crédito (0 a 1) de cada uno
+        stimulus: Vec<String>,
+        answers: Vec<String>,
+        item_scores: Vec<f32>,
+    },
+}
+
+impl GameDetails {
+    /// Variante de juego con puntuación propia, para agrupar estadísticas.
+    /// `None` es la modalidad estándar del juego.
+    pub fn variant(&self) -> Option<String> {
+        match self {
+            // El orden inverso tiene su propio grupo: siempre puntúa en dígitos
+            GameDetails::ReadingSpeed { order: RecallOrder::Backward, .. } => Some(RecallOrder::Backward.name().to_string()),
+            GameDetails::ReadingSpeed { progression, chunking, .. } => {
+                let mut parts = Vec::new();
+                if *progression == DigitProgression::Adaptive {
+                    parts.push(progression.name());
+                }
+                if *chunking != ChunkGrouping::None {
+                    parts.push(chunking.name());
+                }
+                (!parts.is_empty()).then(|| parts.join(" · "))
+            }
+            GameDetails::INumbs { presentation, number_format, .. } => {
+                let mut parts = Vec::new();
+                if *number_format != NumberFormat::Pairs {
+                    parts.push(number_format.name());
+                }
+                if *presentation != PresentationMode::Grid {
+                    parts.push(presentation.name());
+                }
+                (!parts.is_empty()).then(|| parts.join(" · "))
+            }
+            GameDetails::SchulteTable { grid_size, symbols, .. } => {
+                Some(format!("{}×{} · {}", grid_size, grid_size, symbols.name()))
+            }
+            GameDetails::Tachistoscope { stimulus, .. } => Some(stimulus.name().to_string()),
+            GameDetails::PairedAssociates { .. } => Some(RecallMode::Pairs.name().to_string()),
+            GameDetails::DelayedRecall { delay, .. } => Some(format!("Diferido ({})", delay.name())),
+            GameDetails::WordMemory { recall_mode, .. } => {
+                (*recall_mode != RecallMode::Free).then(|| recall_mode.name().to_string())
+            }
+            GameDetails::TextComprehension { reading_mode, .. } => {
+                (*reading_mode != ReadingMode::Normal).then(|| reading_mode.name().to_string())
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Resultado de una ronda de Memoria Numérica
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NumberRound {
+    pub number: String,
+    pub user_answer: String,
+    pub digit_count: usize,
+    pub display_time: Duration,
+    /// Tiempo desde que se ocultó el número hasta que se confirmó la respuesta
+    pub response_time: Duration,
+    pub correct: bool,
+    pub accuracy: f32,
+    #[serde(default)]
+    pub errors: EditErrors,
+}
+
+/// Resultado de un casillero de iNumbs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BoxResult {
+    pub expected: String,
+    pub answer: String,
+    pub correct: bool,
+}
+
+/// Respuesta a una pregunta del Sistema Mayor
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PegResponse {
+    /// Número de 00 a 99
+    pub number: u8,
+    pub direction: PegDirection,
+    pub answer: String,
+    pub correct: bool,
+    pub response_time: Duration,
+}
+
+/// Una exposición del taquistoscopio
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FlashTrial {
+    /// Lo mostrado a la izquierda y a la derecha del punto de fijación
+    pub left: String,
+    pub right: String,
+    pub answer: String,
+    pub correct: bool,
+    /// Anchura del estímulo (dígitos, o separación entre palabras)
+    pub width: usize,
+    /// Tiempo de exposición pedido
+    pub exposure: Duration,
+    /// Tiempo que realmente estuvo en pantalla, medido por frames
+    pub shown_for: Duration,
+    pub frames: u32,
+}
+
+/// Una pareja preguntada en Memoria de Palabras
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PairTrial {
+    /// Palabra mostrada como pista
+    pub cue: String,
+    /// Palabra que la acompañaba
+    pub target: String,
+    pub answer: String,
+    pub correct: bool,
+    /// Tiempo desde que apareció la pista hasta que se confirmó la respuesta
+    pub latency: Duration,
+}
+
+/// Repaso de una tarjeta en una sesión de Tarjetas de Repaso
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FlashcardReview {
+    pub front: String,
+    pub deck: String,
+    pub grade: Grade,
+    /// Días hasta el siguiente repaso
+    pub interval_days: u32,
+    /// Primera vez que se estudiaba la tarjeta
+    pub was_new: bool,
+}
+
+/// Respuesta de Memoria de Palabras aceptada con alguna errata
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NearMiss {
+    pub answer: String,
+    pub target: String,
 }
@@ -19,6 +19,10 @@ pub enum GameType {
     WordMemory,
     TextComprehension,
     INumbs,
+    MajorSystem,
+    SchulteTable,
+    Tachistoscope,
+    Flashcards,
 }
 
 impl GameType {
@@ -28,6 +32,10 @@ impl GameType {
             GameType::WordMemory => "Memoria de Palabras",
             GameType::TextComprehension => "Comprensión de Texto",
             GameType::INumbs => "iNumbs (Números / Rellenar casilleros)",
+            GameType::MajorSystem => "Sistema Mayor",
+            GameType::SchulteTable => "Tabla de Schulte",
+            GameType::Tachistoscope => "Taquistoscopio",
+            GameType::Flashcards => "Tarjetas de Repaso",
         }
     }
     
@@ -37,6 +45,10 @@ impl GameType {
             GameType::WordMemory => "Memoriza las palabras que aparecen y después escríbelas",
             GameType::TextComprehension => "Lee el texto y responde las preguntas",
             GameType::INumbs => "Memoriza secuencias numéricas, opcionalmente completa casilleros",
+            GameType::MajorSystem => "Practica tu lista de imágenes del 00 al 99 (número ↔ palabra)",
+            GameType::SchulteTable => "Encuentra los números en orden mirando solo el centro de la tabla",
+            GameType::Tachistoscope => "Lee de un vistazo lo que aparece a ambos lados del punto central",
+            GameType::Flashcards => "Repasa vocabulario e imágenes justo antes de olvidarlas (repetición espaciada)",
         }
     }
     
@@ -47,6 +59,10 @@ impl GameType {
             GameType::WordMemory,
             GameType::TextComprehension,
             GameType::INumbs,
+            GameType::MajorSystem,
+            GameType::SchulteTable,
+            GameType::Tachistoscope,
+            GameType::Flashcards,
         ]
     }
 }
@@ -77,3 +93,353 @@ impl Difficulty {
         }
     }
 }
+
+/// Cómo cambia la cantidad de dígitos entre rondas en Memoria Numérica
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
+pub enum DigitProgression {
+    /// Cantidad aleatoria dentro del rango de la dificultad
+    #[default]
+    Random,
+    /// Aumento fijo según la ronda
+    Training,
+    /// Escalera 2-arriba/1-abajo según los aciertos
+    Adaptive,
+}
+
+impl DigitProgression {
+    pub fn name(&self) -> &str {
+        match self {
+            DigitProgression::Random => "Aleatoria",
+            DigitProgression::Training => "Training",
+            DigitProgression::Adaptive => "Adaptativa",
+        }
+    }
+}
+
+/// Orden en que se debe escribir el número recordado
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
+pub enum RecallOrder {
+    /// Tal como se mostró (retención)
+    #[default]
+    Forward,
+    /// Del último dígito al primero (memoria de trabajo)
+    Backward,
+}
+
+impl RecallOrder {
+    pub fn name(&self) -> &str {
+        match self {
+            RecallOrder::Forward => "Directo",
+            RecallOrder::Backward => "Inverso",
+        }
+    }
+}
+
+/// Agrupación visual de los dígitos al mostrar un número
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
+pub enum ChunkGrouping {
+    #[default]
+    None,
+    Pairs,
+    Triplets,
+    /// Estilo teléfono: 3-3-4 y después grupos de 4
+    Phone,
+}
+
+impl ChunkGrouping {
+    pub fn all() -> [ChunkGrouping; 4] {
+        [ChunkGrouping::None, ChunkGrouping::Pairs, ChunkGrouping::Triplets, ChunkGrouping::Phone]
+    }
+    
+    pub fn name(&self) -> &str {
+        match self {
+            ChunkGrouping::None => "Sin agrupar",
+            ChunkGrouping::Pairs => "Pares",
+            ChunkGrouping::Triplets => "Tríos",
+            ChunkGrouping::Phone => "Teléfono (3-3-4)",
+        }
+    }
+    
+    /// Tamaño del grupo en la posición `index`, o `None` si no se agrupa
+    fn chunk_size(&self, index: usize) -> Option<usize> {
+        match self {
+            ChunkGrouping::None => None,
+            ChunkGrouping::Pairs => Some(2),
+            ChunkGrouping::Triplets => Some(3),
+            ChunkGrouping::Phone => Some(if index < 2 { 3 } else { 4 }),
+        }
+    }
+    
+    /// Separa los dígitos en grupos con un espacio entre ellos
+    pub fn apply(&self, digits: &str) -> String {
+        let chars: Vec<char> = digits.chars().collect();
+        let mut groups: Vec<String> = Vec::new();
+        let mut start = 0;
+        
+        while start < chars.len() {
+            let size = self.chunk_size(groups.len()).unwrap_or(chars.len());
+            let end = (start + size).min(chars.len());
+            groups.push(chars[start..end].iter().collect());
+            start = end;
+        }
+        
+        groups.join(" ")
+    }
+}
+
+/// Forma de presentar los números en iNumbs
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
+pub enum PresentationMode {
+    /// Todos los números a la vez en una cuadrícula
+    #[default]
+    Grid,
+    /// Un número detrás de otro, con una pausa entre ellos
+    Sequential,
+}
+
+impl PresentationMode {
+    pub fn name(&self) -> &str {
+        match self {
+            PresentationMode::Grid => "Cuadrícula",
+            PresentationMode::Sequential => "Secuencial",
+        }
+    }
+}
+
+/// Formato de los números a memorizar en iNumbs
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
+pub enum NumberFormat {
+    /// 0-9
+    SingleDigit,
+    /// 00-99
+    #[default]
+    Pairs,
+    /// 000-999
+    Triplets,
+    /// 0000-9999
+    Quads,
+    /// 000-111, como en los campeonatos de memoria
+    BinaryTriplets,
+    /// 00-FF
+    Hexadecimal,
+}
+
+impl NumberFormat {
+    pub fn all() -> [NumberFormat; 6] {
+        [
+            NumberFormat::SingleDigit,
+            NumberFormat::Pairs,
+            NumberFormat::Triplets,
+            NumberFormat::Quads,
+            NumberFormat::BinaryTriplets,
+            NumberFormat::Hexadecimal,
+        ]
+    }
+    
+    pub fn name(&self) -> &str {
+        match self {
+            NumberFormat::SingleDigit => "Dígitos (0-9)",
+            NumberFormat::Pairs => "Pares (00-99)",
+            NumberFormat::Triplets => "Tríos (000-999)",
+            NumberFormat::Quads => "Grupos de 4 (0000-9999)",
+            NumberFormat::BinaryTriplets => "Binario (000-111)",
+            NumberFormat::Hexadecimal => "Hexadecimal (00-FF)",
+        }
+    }
+    
+    /// Cantidad de símbolos de cada número
+    pub fn width(&self) -> usize {
+        match self {
+            NumberFormat::SingleDigit => 1,
+            NumberFormat::Pairs | NumberFormat::Hexadecimal => 2,
+            NumberFormat::Triplets | NumberFormat::BinaryTriplets => 3,
+            NumberFormat::Quads => 4,
+        }
+    }
+    
+    pub fn radix(&self) -> u32 {
+        match self {
+            NumberFormat::BinaryTriplets => 2,
+            NumberFormat::Hexadecimal => 16,
+            _ => 10,
+        }
+    }
+    
+    /// Cantidad de valores distintos posibles
+    pub fn value_count(&self) -> u32 {
+        self.radix().pow(self.width() as u32)
+    }
+    
+    /// Escribe `value` con el ancho y la base del formato
+    pub fn format(&self, value: u32) -> String {
+        let width = self.width();
+        match self {
+            NumberFormat::BinaryTriplets => format!("{:0width$b}", value),
+            NumberFormat::Hexadecimal => format!("{:0width$X}", value),
+            _ => format!("{:0width$}", value),
+        }
+    }
+    
+    /// Compara una respuesta con el número esperado según el formato:
+    /// ignora espacios y mayúsculas, pero exige todas las cifras del
+    /// formato, ceros a la izquierda incluidos ("07", no "7").
+    pub fn matches(&self, expected: &str, answer: &str) -> bool {
+        let answer: String = answer.chars().filter(|c| !c.is_whitespace()).collect();
+        if answer.chars().count() != self.width() || !answer.chars().all(|c| c.is_digit(self.radix())) {
+            return false;
+        }
+        
+        match (
+            u32::from_str_radix(expected.trim(), self.radix()),
+            u32::from_str_radix(&answer, self.radix()),
+        ) {
+            (Ok(expected), Ok(answer)) => expected == answer,
+            _ => false,
+        }
+    }
+}
+
+/// Sentido de una pregunta del Sistema Mayor
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum PegDirection {
+    /// Se muestra el número y se pide la palabra
+    NumberToWord,
+    /// Se muestra la palabra y se pide el número
+    WordToNumber,
+}
+
+/// Forma de presentar el texto en Comprensión de Texto
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
+pub enum ReadingMode {
+    /// Texto completo, a ritmo libre
+    #[default]
+    Normal,
+    /// Palabra a palabra en el centro de la pantalla
+    Rsvp,
+    /// Texto completo con un marcador que marca el ritmo línea a línea
+    Pacer,
+}
+
+impl ReadingMode {
+    pub fn all() -> [ReadingMode; 3] {
+        [ReadingMode::Normal, ReadingMode::Rsvp, ReadingMode::Pacer]
+    }
+    
+    pub fn name(&self) -> &str {
+        match self {
+            ReadingMode::Normal => "Normal",
+            ReadingMode::Rsvp => "RSVP",
+            ReadingMode::Pacer => "Marcador",
+        }
+    }
+}
+
+/// Cómo se puntúa el recuerdo en Memoria de Palabras
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
+pub enum RecallMode {
+    /// Cuenta cada palabra recordada, en cualquier orden
+    #[default]
+    Free,
+    /// Cada palabra debe escribirse en su posición original
+    Serial,
+    /// Parejas de palabras: se muestra la primera y se pide la segunda
+    Pairs,
+}
+
+impl RecallMode {
+    pub fn all() -> [RecallMode; 3] {
+        [RecallMode::Free, RecallMode::Serial, RecallMode::Pairs]
+    }
+    
+    pub fn name(&self) -> &str {
+        match self {
+            RecallMode::Free => "Libre",
+            RecallMode::Serial => "En orden",
+            RecallMode::Pairs => "Parejas",
+        }
+    }
+}
+
+/// Espera hasta la prueba de recuerdo diferido
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum RecallDelay {
+    TenMinutes,
+    OneDay,
+    OneWeek,
+}
+
+impl RecallDelay {
+    pub fn all() -> [RecallDelay; 3] {
+        [RecallDelay::TenMinutes, RecallDelay::OneDay, RecallDelay::OneWeek]
+    }
+    
+    pub fn name(&self) -> &str {
+        match self {
+            RecallDelay::TenMinutes => "10 minutos",
+            RecallDelay::OneDay => "1 día",
+            RecallDelay::OneWeek => "1 semana",
+        }
+    }
+    
+    pub fn duration(&self) -> std::time::Duration {
+        let minutes = match self {
+            RecallDelay::TenMinutes => 10,
+            RecallDelay::OneDay => 24 * 60,
+            RecallDelay::OneWeek => 7 * 24 * 60,
+        };
+        std::time::Duration::from_secs(minutes * 60)
+    }
+}
+
+/// Símbolos de la tabla de Schulte
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
+pub enum SchulteSymbols {
+    #[default]
+    Numbers,
+    Letters,
+}
+
+impl SchulteSymbols {
+    /// Alfabeto usado para las tablas de letras
+    const LETTERS: &'static str = "ABCDEFGHIJKLMNÑOPQRSTUVWXYZ";
+    
+    pub fn name(&self) -> &str {
+        match self {
+            SchulteSymbols::Numbers => "Números",
+            SchulteSymbols::Letters => "Letras",
+        }
+    }
+    
+    /// Mayor tamaño de tabla que se puede llenar con estos símbolos
+    pub fn max_grid_size(&self) -> usize {
+        match self {
+            SchulteSymbols::Numbers => 7,
+            SchulteSymbols::Letters => 5,
+        }
+    }
+    
+    /// Símbolo que ocupa la posición `index` (desde 0) en el orden a seguir
+    pub fn symbol(&self, index: usize) -> String {
+        match self {
+            SchulteSymbols::Numbers => (index + 1).to_string(),
+            SchulteSymbols::Letters => Self::LETTERS.chars().nth(index).map(String::from).unwrap_or_default(),
+        }
+    }
+}
+
+/// Tipo de estímulo del taquistoscopio
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
+pub enum FlashStimulus {
+    #[default]
+    Digits,
+    Words,
+}
+
+impl FlashStimulus {
+    pub fn name(&self) -> &str {
+        match self {
+            FlashStimulus::Digits => "Dígitos",
+            FlashStimulus::Words => "Palabras",
+        }
+    }
+}
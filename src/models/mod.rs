@@ -7,8 +7,16 @@ mod game_types;
 mod game_config;
 mod game_result;
 mod storage;
+mod passage;
+mod word_list;
+mod flashcard;
+pub mod text_import;
+pub mod epub;
 
 pub use game_types::*;
 pub use game_config::*;
 pub use game_result::*;
 pub use storage::*;
+pub use passage::*;
+pub use word_list::*;
+pub use flashcard::*;
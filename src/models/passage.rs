@@ -0,0 +1,285 @@
+//! Textos y preguntas para Comprensión de Texto
+//!
+//! Además de los textos incluidos, se cargan los archivos `.json` y `.toml`
+//! del directorio `passages` dentro del directorio de datos del usuario.
+//! Cada archivo contiene un texto o una lista de textos (ver README).
+//! Los `.txt`, `.md` y `.epub` de ese directorio se importan con preguntas generadas.
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+use super::Difficulty;
+use super::text_import;
+use crate::utils::normalize::loosely_equal;
+use crate::utils::readability::Readability;
+
+/// Texto para leer con sus preguntas
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Passage {
+    pub id: String,
+    #[serde(default)]
+    pub title: String,
+    pub difficulty: Difficulty,
+    pub text: String,
+    pub questions: Vec<Question>,
+}
+
+/// Pregunta sobre el texto
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Question {
+    /// Enunciado. En las de completar, los huecos se marcan con `___`.
+    pub question: String,
+    #[serde(flatten)]
+    pub kind: QuestionKind,
+}
+
+/// Tipo de pregunta. Se distingue por los campos presentes, así los
+/// archivos con solo opción múltiple siguen siendo válidos.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum QuestionKind {
+    MultipleChoice {
+        options: Vec<String>,
+        /// Índice (desde 0) de la opción correcta
+        correct_answer: usize,
+    },
+    /// Frase del texto con palabras ocultas que hay que escribir
+    Cloze {
+        blanks: Vec<ClozeBlank>,
+    },
+}
+
+/// Palabra oculta en una pregunta de completar
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClozeBlank {
+    pub answer: String,
+    /// Otras respuestas que también se aceptan
+    #[serde(default)]
+    pub synonyms: Vec<String>,
+}
+
+/// Marca de hueco en el enunciado de las preguntas de completar
+pub const CLOZE_GAP: &str = "___";
+
+impl ClozeBlank {
+    /// Acepta la respuesta o un sinónimo sin importar mayúsculas ni tildes
+    pub fn accepts(&self, answer: &str) -> bool {
+        loosely_equal(&self.answer, answer) || self.synonyms.iter().any(|s| loosely_equal(s, answer))
+    }
+}
+
+impl Question {
+    /// Número de respuestas que hay que dar: una por hueco, o una elección
+    pub fn answer_slots(&self) -> usize {
+        match &self.kind {
+            QuestionKind::MultipleChoice { .. } => 1,
+            QuestionKind::Cloze { blanks } => blanks.len(),
+        }
+    }
+
+    /// Comprueba las respuestas del usuario. En opción múltiple la única
+    /// respuesta es el índice elegido; en las de completar, el texto de
+    /// cada hueco, y solo cuenta como acierto si están todos bien.
+    pub fn is_correct(&self, answers: &[String]) -> bool {
+        match &self.kind {
+            QuestionKind::MultipleChoice { correct_answer, .. } => answers
+                .first()
+                .and_then(|a| a.parse::<usize>().ok())
+                .is_some_and(|a| a == *correct_answer),
+            QuestionKind::Cloze { blanks } => {
+                answers.len() == blanks.len()
+                    && blanks.iter().zip(answers).all(|(blank, answer)| blank.accepts(answer))
+            }
+        }
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        match &self.kind {
+            QuestionKind::MultipleChoice { options, correct_answer } => {
+                if options.len() < 2 {
+                    return Err("necesita al menos dos opciones".to_string());
+                }
+                if *correct_answer >= options.len() {
+                    return Err(format!(
+                        "indica la respuesta {} pero solo tiene {} opciones",
+                        correct_answer,
+                        options.len()
+                    ));
+                }
+            }
+            QuestionKind::Cloze { blanks } => {
+                let gaps = self.question.matches(CLOZE_GAP).count();
+                if blanks.is_empty() {
+                    return Err("no tiene huecos".to_string());
+                }
+                if gaps != blanks.len() {
+                    return Err(format!(
+                        "tiene {} huecos ({}) pero {} respuestas",
+                        gaps,
+                        CLOZE_GAP,
+                        blanks.len()
+                    ));
+                }
+                if blanks.iter().any(|b| b.answer.trim().is_empty()) {
+                    return Err("tiene un hueco sin respuesta".to_string());
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Texto tal como se escribe en un archivo: sin dificultad, se calcula
+/// a partir de la legibilidad del texto
+#[derive(Deserialize)]
+struct PassageEntry {
+    id: String,
+    #[serde(default)]
+    title: String,
+    #[serde(default)]
+    difficulty: Option<Difficulty>,
+    text: String,
+    questions: Vec<Question>,
+}
+
+impl From<PassageEntry> for Passage {
+    fn from(entry: PassageEntry) -> Self {
+        Self {
+            difficulty: entry.difficulty.unwrap_or_else(|| Readability::analyze(&entry.text).difficulty()),
+            id: entry.id,
+            title: entry.title,
+            text: entry.text,
+            questions: entry.questions,
+        }
+    }
+}
+
+/// Un archivo puede tener un solo texto o varios
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum PassageFile {
+    Many(Vec<PassageEntry>),
+    One(PassageEntry),
+}
+
+/// En TOML la raíz es una tabla: varios textos van en `[[passages]]`
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum TomlPassageFile {
+    Many { passages: Vec<PassageEntry> },
+    One(PassageEntry),
+}
+
+impl PassageFile {
+    fn parse(path: &Path, content: &str) -> Result<Vec<Passage>, String> {
+        if path.extension().is_some_and(|ext| ext == "toml") {
+            return match toml::from_str::<TomlPassageFile>(content).map_err(|e| e.to_string())? {
+                TomlPassageFile::Many { passages } => Ok(passages.into_iter().map(Passage::from).collect()),
+                TomlPassageFile::One(passage) => Ok(vec![passage.into()]),
+            };
+        }
+        match serde_json::from_str::<PassageFile>(content).map_err(|e| e.to_string())? {
+            PassageFile::Many(passages) => Ok(passages.into_iter().map(Passage::from).collect()),
+            PassageFile::One(passage) => Ok(vec![passage.into()]),
+        }
+    }
+}
+
+/// Textos disponibles y problemas encontrados al cargarlos
+#[derive(Debug, Clone, Default)]
+pub struct PassageLibrary {
+    pub passages: Vec<Passage>,
+    pub errors: Vec<String>,
+}
+
+impl Passage {
+    /// Comprueba que el texto se pueda jugar
+    pub fn validate(&self) -> Result<(), String> {
+        if self.id.trim().is_empty() {
+            return Err("falta el campo \"id\"".to_string());
+        }
+        if self.text.trim().is_empty() {
+            return Err(format!("«{}»: el texto está vacío", self.id));
+        }
+        if self.questions.is_empty() {
+            return Err(format!("«{}»: no tiene preguntas", self.id));
+        }
+        for (i, question) in self.questions.iter().enumerate() {
+            question
+                .validate()
+                .map_err(|e| format!("«{}»: la pregunta {} {}", self.id, i + 1, e))?;
+        }
+        Ok(())
+    }
+}
+
+impl PassageLibrary {
+    /// Carga todos los `.json` y `.toml` de `dir` e importa sus textos en
+    /// partes de `passage_words` palabras. Un directorio inexistente no es un error.
+    pub fn load(dir: &Path, passage_words: usize) -> Self {
+        let mut library = Self::default();
+        let Ok(entries) = fs::read_dir(dir) else {
+            return library;
+        };
+        
+        let mut paths: Vec<_> = entries
+            .filter_map(|e| e.ok().map(|e| e.path()))
+            .filter(|p| {
+                (p.extension().is_some_and(|ext| ext == "json" || ext == "toml") && !text_import::is_cache_file(p))
+                    || text_import::is_importable(p)
+            })
+            .collect();
+        paths.sort();
+        
+        for path in paths {
+            let name = path.file_name().unwrap_or_default().to_string_lossy().to_string();
+            let passages = if text_import::is_importable(&path) {
+                match text_import::import_text_file(&path, passage_words) {
+                    Ok(imported) => imported.passages,
+                    Err(e) => {
+                        library.errors.push(e);
+                        continue;
+                    }
+                }
+            } else {
+                let parsed = fs::read_to_string(&path)
+                    .map_err(|e| e.to_string())
+                    .and_then(|content| PassageFile::parse(&path, &content));
+                
+                match parsed {
+                    Ok(passages) => passages,
+                    Err(e) => {
+                        library.errors.push(format!("{}: {}", name, e));
+                        continue;
+                    }
+                }
+            };
+            
+            for passage in passages {
+                match passage.validate() {
+                    Ok(()) if library.contains(&passage.id) => {
+                        library.errors.push(format!("{}: id «{}» repetido", name, passage.id));
+                    }
+                    Ok(()) => library.passages.push(passage),
+                    Err(e) => library.errors.push(format!("{}: {}", name, e)),
+                }
+            }
+        }
+        
+        library
+    }
+    
+    pub fn contains(&self, id: &str) -> bool {
+        self.passages.iter().any(|p| p.id == id)
+    }
+    
+    /// Importa un archivo de texto o un libro y devuelve su título y los ids
+    /// de sus partes, en orden. Si ya estaba importado, sus partes se sustituyen.
+    pub fn import_file(&mut self, path: &Path, passage_words: usize) -> Result<(String, Vec<String>), String> {
+        let imported = text_import::import_text_file(path, passage_words)?;
+        let ids: Vec<String> = imported.passages.iter().map(|p| p.id.clone()).collect();
+        self.passages.retain(|p| !ids.contains(&p.id));
+        self.passages.extend(imported.passages);
+        Ok((imported.title, ids))
+    }
+}
@@ -1,16 +1,107 @@
 //! Persistencia de datos
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::time::{Duration, SystemTime};
 use std::fs;
 use std::path::PathBuf;
-use super::{GameResult, GameConfig, GameType};
+use super::{Card, GameResult, GameConfig, GameType, GameDetails, NumberFormat, RecallDelay, RecallMode};
 
 /// Almacenamiento persistente de la aplicación
 #[derive(Debug, Serialize, Deserialize)]
 pub struct GameStorage {
     pub results: Vec<GameResult>,
     pub configs: HashMap<GameType, GameConfig>,
+    /// Lista de imágenes del Sistema Mayor (00-99 → palabra)
+    #[serde(default)]
+    pub pegs: BTreeMap<u8, String>,
+    /// Avance en los textos y libros importados, por ruta del archivo
+    #[serde(default)]
+    pub books: BTreeMap<String, BookProgress>,
+    /// Pruebas de recuerdo diferido programadas y aún sin hacer
+    #[serde(default)]
+    pub pending_recalls: Vec<PendingRecall>,
+    /// Tarjetas de repaso espaciado, con su historial de repasos
+    #[serde(default)]
+    pub cards: Vec<Card>,
+}
+
+/// Prueba de recuerdo diferido programada al terminar una partida
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PendingRecall {
+    pub game_type: GameType,
+    pub delay: RecallDelay,
+    /// Fin de la partida original; identifica la prueba
+    pub studied_at: SystemTime,
+    /// Acierto (0 a 1) al terminar la partida original
+    pub immediate_score: f32,
+    /// Palabras o números mostrados, en orden
+    pub stimulus: Vec<String>,
+    /// Cómo se recuerdan las palabras (Memoria de Palabras)
+    #[serde(default)]
+    pub recall_mode: RecallMode,
+    /// Formato de los números (iNumbs)
+    #[serde(default)]
+    pub number_format: NumberFormat,
+}
+
+/// Por dónde va la lectura de un texto o libro importado
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BookProgress {
+    pub title: String,
+    /// Índice (desde 0) de la siguiente parte por leer
+    pub next_part: usize,
+    pub total_parts: usize,
+    pub chunks: Vec<ChunkLog>,
+}
+
+/// Lectura de una parte de un libro
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChunkLog {
+    pub part: usize,
+    pub words: usize,
+    pub wpm: f32,
+    pub timestamp: SystemTime,
+}
+
+impl PendingRecall {
+    pub fn due_at(&self) -> SystemTime {
+        self.studied_at + self.delay.duration()
+    }
+
+    pub fn is_due(&self) -> bool {
+        SystemTime::now() >= self.due_at()
+    }
+
+    /// Resultado de la prueba: `item_scores` es el crédito (0 a 1) de cada
+    /// elemento de `stimulus`
+    pub fn to_result(&self, stimulus: Vec<String>, answers: Vec<String>, item_scores: Vec<f32>) -> GameResult {
+        let score = item_scores.iter().sum::<f32>() / item_scores.len().max(1) as f32;
+        GameResult {
+            game_type: self.game_type.clone(),
+            score: score * 100.0,
+            details: GameDetails::DelayedRecall {
+                delay: self.delay,
+                studied_at: self.studied_at,
+                elapsed: self.studied_at.elapsed().unwrap_or_default(),
+                immediate_score: self.immediate_score,
+                stimulus,
+                answers,
+                item_scores,
+            },
+            timestamp: SystemTime::now(),
+        }
+    }
+}
+
+impl BookProgress {
+    pub fn is_finished(&self) -> bool {
+        self.total_parts > 0 && self.next_part >= self.total_parts
+    }
+
+    pub fn last_read(&self) -> Option<SystemTime> {
+        self.chunks.last().map(|c| c.timestamp)
+    }
 }
 
 impl GameStorage {
@@ -22,6 +113,10 @@ impl GameStorage {
         Self {
             results: Vec::new(),
             configs: HashMap::new(),
+            pegs: BTreeMap::new(),
+            books: BTreeMap::new(),
+            pending_recalls: Vec::new(),
+            cards: Vec::new(),
         }
     }
 
@@ -35,6 +130,102 @@ impl GameStorage {
         self.save().ok();
     }
 
+    pub fn set_pegs(&mut self, pegs: BTreeMap<u8, String>) {
+        self.pegs = pegs;
+        self.save().ok();
+    }
+
+    pub fn set_book_progress(&mut self, path: String, progress: BookProgress) {
+        self.books.insert(path, progress);
+        self.save().ok();
+    }
+
+    pub fn set_cards(&mut self, cards: Vec<Card>) {
+        self.cards = cards;
+        self.save().ok();
+    }
+
+    /// Tarjetas ya estudiadas que toca repasar hoy
+    pub fn due_card_count(&self) -> usize {
+        let end = super::end_of_today();
+        self.cards.iter().filter(|c| c.is_due(end)).count()
+    }
+
+    pub fn schedule_recall(&mut self, recall: PendingRecall) {
+        self.pending_recalls.push(recall);
+        self.save().ok();
+    }
+
+    /// Quita la prueba diferida ya hecha de la partida terminada en `studied_at`
+    pub fn complete_recall(&mut self, studied_at: SystemTime) {
+        self.pending_recalls.retain(|r| r.studied_at != studied_at);
+        self.save().ok();
+    }
+
+    /// Curva del olvido de un juego: un punto por cada espera ya probada
+    pub fn get_forgetting_curve(&self, game_type: &GameType) -> Vec<RetentionPoint> {
+        RecallDelay::all()
+            .into_iter()
+            .filter_map(|delay| {
+                let retentions: Vec<f32> = self
+                    .get_results_for_game(game_type)
+                    .iter()
+                    .filter_map(|r| match &r.details {
+                        GameDetails::DelayedRecall { delay: d, immediate_score, .. } if *d == delay => {
+                            let immediate = immediate_score.max(0.01);
+                            Some((r.score / 100.0 / immediate).min(1.0))
+                        }
+                        _ => None,
+                    })
+                    .collect();
+                (!retentions.is_empty()).then(|| RetentionPoint {
+                    delay,
+                    retention: retentions.iter().sum::<f32>() / retentions.len() as f32,
+                    tests: retentions.len(),
+                })
+            })
+            .collect()
+    }
+
+    /// Números del Sistema Mayor con mayor tiempo medio de respuesta,
+    /// del más lento al más rápido
+    pub fn get_slowest_pegs(&self, count: usize) -> Vec<u8> {
+        let mut times: BTreeMap<u8, (Duration, u32)> = BTreeMap::new();
+        for result in &self.results {
+            if let GameDetails::MajorSystem { responses } = &result.details {
+                for response in responses {
+                    let entry = times.entry(response.number).or_default();
+                    entry.0 += response.response_time;
+                    entry.1 += 1;
+                }
+            }
+        }
+        
+        let mut averages: Vec<(u8, Duration)> = times
+            .into_iter()
+            .filter(|(number, _)| self.pegs.contains_key(number))
+            .map(|(number, (total, n))| (number, total / n))
+            .collect();
+        averages.sort_by_key(|(_, avg)| std::cmp::Reverse(*avg));
+        averages.into_iter().take(count).map(|(number, _)| number).collect()
+    }
+
+    /// Identificadores de los textos leídos más recientemente, del último al primero
+    pub fn get_recent_passage_ids(&self, count: usize) -> Vec<String> {
+        let mut results: Vec<&GameResult> = self.get_results_for_game(&GameType::TextComprehension);
+        results.sort_by_key(|r| std::cmp::Reverse(r.timestamp));
+        results
+            .iter()
+            .filter_map(|r| match &r.details {
+                GameDetails::TextComprehension { passage_id, .. } if !passage_id.is_empty() => {
+                    Some(passage_id.clone())
+                }
+                _ => None,
+            })
+            .take(count)
+            .collect()
+    }
+
     pub fn get_results_for_game(&self, game_type: &GameType) -> Vec<&GameResult> {
         self.results
             .iter()
@@ -49,15 +240,61 @@ impl GameStorage {
             return GameStats::default();
         }
         
-        let total_games = results.len();
-        let best_score = results.iter().map(|r| r.score).fold(0.0f32, |a, b| a.max(b));
+        // Una prueba diferida repite una partida ya contada y puntúa sobre otra
+        // escala: solo cuenta en su propia variante
+        let games = results
+            .iter()
+            .filter(|r| !matches!(r.details, GameDetails::DelayedRecall { .. }));
+        let total_games = games.clone().count();
+        let best_score = games.map(|r| r.score).fold(0.0f32, |a, b| a.max(b));
+        
+        // Las variantes puntúan en escalas distintas, así que se agrupan aparte
+        let mut variants: Vec<VariantStats> = Vec::new();
+        for result in &results {
+            let name = result.details.variant().unwrap_or_else(|| STANDARD_VARIANT.to_string());
+            match variants.iter_mut().find(|v| v.name == name) {
+                Some(variant) => {
+                    variant.total_games += 1;
+                    variant.best_score = variant.best_score.max(result.score);
+                    variant.total_score += result.score;
+                }
+                None => variants.push(VariantStats {
+                    name,
+                    total_games: 1,
+                    best_score: result.score,
+                    total_score: result.score,
+                }),
+            }
+        }
         
         GameStats {
             total_games,
             best_score,
+            variants,
         }
     }
 
+    /// Errores de iNumbs según la posición en la secuencia, sumando todas las partidas.
+    /// Cada partida se reparte en `buckets` tramos para comparar secuencias de distinta longitud.
+    pub fn get_inumbs_position_errors(&self, buckets: usize) -> Vec<PositionStats> {
+        let buckets = buckets.max(1);
+        let mut stats = vec![PositionStats::default(); buckets];
+        
+        for result in &self.results {
+            if let GameDetails::INumbs { boxes, .. } = &result.details {
+                for (i, b) in boxes.iter().enumerate() {
+                    let bucket = i * buckets / boxes.len();
+                    stats[bucket].total += 1;
+                    if !b.correct {
+                        stats[bucket].errors += 1;
+                    }
+                }
+            }
+        }
+        
+        stats
+    }
+
     pub fn get_all_results(&self) -> Vec<GameResult> {
         self.results.clone()
     }
@@ -67,16 +304,30 @@ impl GameStorage {
         self.save().ok();
     }
 
-    fn get_save_path() -> PathBuf {
+    /// Directorio de datos del usuario (partidas guardadas y contenido propio)
+    pub fn data_dir() -> PathBuf {
         let mut path = dirs::config_dir().unwrap_or_else(|| PathBuf::from("."));
         path.push("superlectura_games");
         if !path.exists() {
             fs::create_dir_all(&path).ok();
         }
-        path.push("save_data.json");
         path
     }
 
+    /// Directorio con los textos propios para Comprensión de Texto
+    pub fn passages_dir() -> PathBuf {
+        Self::data_dir().join("passages")
+    }
+
+    /// Directorio con las listas propias para Memoria de Palabras
+    pub fn word_lists_dir() -> PathBuf {
+        Self::data_dir().join("word_lists")
+    }
+
+    fn get_save_path() -> PathBuf {
+        Self::data_dir().join("save_data.json")
+    }
+
     fn save(&self) -> Result<(), Box<dyn std::error::Error>> {
         let path = Self::get_save_path();
         let json = serde_json::to_string_pretty(self)?;
@@ -98,9 +349,66 @@ impl Default for GameStorage {
     }
 }
 
+/// Nombre del grupo de estadísticas para la modalidad estándar
+const STANDARD_VARIANT: &str = "Estándar";
+
 /// Estadísticas de un juego
 #[derive(Debug, Clone, Default)]
 pub struct GameStats {
     pub total_games: usize,
     pub best_score: f32,
+    pub variants: Vec<VariantStats>,
+}
+
+impl GameStats {
+    /// Indica si se ha jugado alguna variante además de la estándar
+    pub fn has_variants(&self) -> bool {
+        self.variants.len() > 1 || self.variants.iter().any(|v| v.name != STANDARD_VARIANT)
+    }
+}
+
+/// Estadísticas de una variante concreta de un juego
+#[derive(Debug, Clone, Default)]
+pub struct VariantStats {
+    pub name: String,
+    pub total_games: usize,
+    pub best_score: f32,
+    total_score: f32,
+}
+
+impl VariantStats {
+    pub fn average_score(&self) -> f32 {
+        if self.total_games == 0 {
+            0.0
+        } else {
+            self.total_score / self.total_games as f32
+        }
+    }
+}
+
+/// Punto de la curva del olvido
+#[derive(Debug, Clone, Copy)]
+pub struct RetentionPoint {
+    pub delay: RecallDelay,
+    /// Acierto diferido entre acierto inmediato, de media (0 a 1)
+    pub retention: f32,
+    /// Pruebas hechas con esta espera
+    pub tests: usize,
+}
+
+/// Aciertos y errores acumulados en un tramo de posiciones
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PositionStats {
+    pub errors: usize,
+    pub total: usize,
+}
+
+impl PositionStats {
+    pub fn error_rate(&self) -> f32 {
+        if self.total == 0 {
+            0.0
+        } else {
+            self.errors as f32 / self.total as f32
+        }
+    }
 }
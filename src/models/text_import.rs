@@ -0,0 +1,499 @@
+//! Importación de textos y libros (.txt / .md / .epub) para Comprensión de Texto
+//!
+//! Un libro con capítulos (los de un EPUB, los títulos de un Markdown o las
+//! líneas «Capítulo …» de un texto) se lee capítulo a capítulo; un texto sin
+//! ellos se divide en partes de una longitud aproximada. Para cada parte
+//! se generan preguntas de completar y de reconocer frases, sin conexión a
+//! ningún servicio. El resultado se guarda junto al archivo original como
+//! `<archivo>.questions.json` y se reutiliza mientras el original no cambie.
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use super::epub::{Book, Chapter};
+use super::{ClozeBlank, Passage, Question, QuestionKind, CLOZE_GAP};
+use crate::utils::normalize::normalize;
+use crate::utils::readability::Readability;
+
+/// Palabras por parte si no se indica otra cosa
+pub const DEFAULT_PASSAGE_WORDS: usize = 250;
+
+/// Sufijo del archivo con las preguntas generadas
+const CACHE_SUFFIX: &str = ".questions.json";
+
+/// Versión del formato de la caché; al cambiarla se regeneran las preguntas
+const CACHE_VERSION: u32 = 1;
+
+/// Palabras frecuentes que no sirven como hueco ni como señuelo
+const STOPWORDS: &[&str] = &[
+    "porque", "cuando", "donde", "entre", "sobre", "hasta", "desde", "tambien", "aunque",
+    "mientras", "entonces", "siempre", "mucho", "muchos", "mucha", "muchas", "todos", "todas",
+    "otros", "otras", "estos", "estas", "esos", "esas", "aquel", "aquella", "aquellos", "puede",
+    "pueden", "habia", "habian", "tiene", "tienen", "estan", "estaba", "estaban", "seria", "hacia",
+    "segun", "durante", "antes", "despues", "ademas", "nuestro", "nuestra", "ellos", "ellas",
+    "quien", "cual", "cuales", "parte", "hacer", "forma", "tanto", "cada", "misma", "mismo",
+    "which", "their", "there", "these", "those", "would", "could", "should", "about", "where",
+    "other", "being", "after", "before", "while", "because",
+];
+
+/// Texto importado con sus preguntas, tal como se guarda en caché
+#[derive(Serialize, Deserialize)]
+pub struct ImportedText {
+    #[serde(default)]
+    pub version: u32,
+    /// Título del libro, o el nombre del archivo
+    #[serde(default)]
+    pub title: String,
+    pub passage_words: usize,
+    /// Cada parte es un capítulo entero y `passage_words` no se usó
+    #[serde(default)]
+    pub by_chapter: bool,
+    pub passages: Vec<Passage>,
+}
+
+/// Indica si el archivo se puede importar
+pub fn is_importable(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext == "txt" || ext == "md" || ext == "epub")
+}
+
+/// Indica si el archivo es una caché de preguntas generadas
+pub fn is_cache_file(path: &Path) -> bool {
+    path.to_string_lossy().ends_with(CACHE_SUFFIX)
+}
+
+pub fn cache_path(source: &Path) -> PathBuf {
+    let mut name = source.as_os_str().to_owned();
+    name.push(CACHE_SUFFIX);
+    PathBuf::from(name)
+}
+
+/// Importa un archivo de texto, usando la caché si está al día
+pub fn import_text_file(path: &Path, passage_words: usize) -> Result<ImportedText, String> {
+    let name = path.file_name().unwrap_or_default().to_string_lossy().to_string();
+    if !is_importable(path) {
+        return Err(format!("{}: solo se pueden importar archivos .txt, .md o .epub", name));
+    }
+    let modified = fs::metadata(path)
+        .and_then(|m| m.modified())
+        .map_err(|e| format!("{}: {}", name, e))?;
+
+    // Los ids empiezan por la ruta completa, que distingue archivos con el
+    // mismo nombre en otras carpetas
+    let source = fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf()).to_string_lossy().to_string();
+    let id_prefix = format!("{}#", source);
+
+    let cache = cache_path(path);
+    let cache_is_fresh = fs::metadata(&cache)
+        .and_then(|m| m.modified())
+        .is_ok_and(|cached| cached >= modified);
+    if cache_is_fresh {
+        let cached = fs::read_to_string(&cache)
+            .ok()
+            .and_then(|json| serde_json::from_str::<ImportedText>(&json).ok());
+        if let Some(imported) = cached
+            .filter(|s| s.version == CACHE_VERSION && (s.by_chapter || s.passage_words == passage_words))
+            .filter(|s| s.passages.iter().all(|p| p.id.starts_with(&id_prefix)))
+        {
+            return Ok(imported);
+        }
+    }
+
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy().to_string();
+    let book = if path.extension().is_some_and(|ext| ext == "epub") {
+        Book::open_epub(path).map_err(|e| format!("{}: {}", name, e))?
+    } else {
+        let text = fs::read_to_string(path).map_err(|e| format!("{}: {}", name, e))?;
+        let chapters = if path.extension().is_some_and(|ext| ext == "md") {
+            markdown_chapters(&text, &stem)
+        } else {
+            text_chapters(&text, &stem)
+        };
+        Book { title: stem, chapters }
+    };
+
+    let by_chapter = book.chapters.len() > 1;
+    let passages = generate_passages(&source, &book.chapters, (!by_chapter).then_some(passage_words));
+    if passages.is_empty() {
+        return Err(format!("{}: no se pudieron generar preguntas", name));
+    }
+
+    // Si no se puede escribir la caché, se vuelve a generar la próxima vez
+    let imported = ImportedText { version: CACHE_VERSION, title: book.title, passage_words, by_chapter, passages };
+    if let Ok(json) = serde_json::to_string_pretty(&imported) {
+        fs::write(&cache, json).ok();
+    }
+    Ok(imported)
+}
+
+/// Capítulos de un Markdown: cada título de nivel 1 o 2 empieza uno. El
+/// texto anterior al primer título lleva el nombre del archivo.
+fn markdown_chapters(text: &str, stem: &str) -> Vec<Chapter> {
+    let mut sections: Vec<(String, String)> = vec![(stem.to_string(), String::new())];
+    let mut in_code = false;
+    for line in text.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with("```") {
+            in_code = !in_code;
+        }
+        let level = trimmed.chars().take_while(|&c| c == '#').count();
+        if !in_code && (1..=2).contains(&level) && trimmed[level..].starts_with(' ') {
+            sections.push((strip_links(trimmed[level..].trim()).replace(['*', '_', '`'], ""), String::new()));
+            continue;
+        }
+        let (_, section) = sections.last_mut().expect("siempre hay una sección");
+        section.push_str(line);
+        section.push('\n');
+    }
+    collect_chapters(sections.into_iter().map(|(title, text)| (title, strip_markdown(&text))))
+}
+
+/// Capítulos de un texto plano: una línea tras otra en blanco como
+/// «Capítulo 3», «CHAPTER IV. The Storm» o «Parte 2» abre uno nuevo
+fn text_chapters(text: &str, stem: &str) -> Vec<Chapter> {
+    const HEADINGS: &[&str] = &["capitulo", "chapter", "parte", "part", "libro", "book"];
+    let is_number = |word: &str| {
+        let word = word.trim_end_matches(['.', ':']);
+        !word.is_empty()
+            && (word.chars().all(|c| c.is_ascii_digit()) || word.chars().all(|c| "IVXLC".contains(c)))
+    };
+    // Tras el número solo puede ir un título separado por «.» o «:»; así una
+    // frase como «Part I of the plan…» no se toma por encabezado
+    let is_heading_number = |words: &[&str]| {
+        is_number(words[1]) && (words.len() == 2 || words[1].ends_with(['.', ':']))
+    };
+
+    let mut sections: Vec<(String, String)> = vec![(stem.to_string(), String::new())];
+    let mut after_blank = true;
+    for line in text.lines() {
+        let trimmed = line.trim();
+        let words: Vec<&str> = trimmed.split_whitespace().collect();
+        let is_heading = after_blank
+            && words.len() <= 10
+            && words.len() >= 2
+            && HEADINGS.contains(&normalize(words[0]).as_str())
+            && is_heading_number(&words);
+        after_blank = trimmed.is_empty();
+        if is_heading {
+            sections.push((trimmed.to_string(), String::new()));
+            continue;
+        }
+        let (_, section) = sections.last_mut().expect("siempre hay una sección");
+        section.push_str(line);
+        section.push('\n');
+    }
+    collect_chapters(sections.into_iter())
+}
+
+/// Descarta las secciones sin texto (p. ej. lo que precede al primer capítulo)
+fn collect_chapters(sections: impl Iterator<Item = (String, String)>) -> Vec<Chapter> {
+    sections
+        .filter(|(_, text)| !text.trim().is_empty())
+        .map(|(title, text)| Chapter { title, text })
+        .collect()
+}
+
+/// Quita la sintaxis de Markdown más común y deja el texto corrido
+fn strip_markdown(text: &str) -> String {
+    let mut out = String::new();
+    let mut in_code = false;
+    for line in text.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with("```") {
+            in_code = !in_code;
+            continue;
+        }
+        // Los títulos no suelen ser frases completas
+        if in_code || trimmed.starts_with('#') {
+            out.push('\n');
+            continue;
+        }
+        let mut line = trimmed.trim_start_matches(['>', '-', '*', '+']).trim_start();
+        let digits = line.chars().take_while(|c| c.is_ascii_digit()).count();
+        if digits > 0 && line[digits..].starts_with(". ") {
+            line = &line[digits + 2..];
+        }
+        out.push_str(&strip_links(line).replace(['*', '_', '`'], ""));
+        out.push('\n');
+    }
+    out
+}
+
+/// Sustituye `[texto](url)` por `texto`
+fn strip_links(line: &str) -> String {
+    let mut out = String::new();
+    let mut rest = line;
+    while let Some(open) = rest.find('[') {
+        let Some(close) = rest[open..].find("](").map(|i| open + i) else {
+            break;
+        };
+        let Some(end) = rest[close..].find(')').map(|i| close + i) else {
+            break;
+        };
+        out.push_str(&rest[..open]);
+        out.push_str(&rest[open + 1..close]);
+        rest = &rest[end + 1..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Divide el texto en párrafos (separados por líneas en blanco) y cada
+/// párrafo en frases
+fn split_paragraphs(text: &str) -> Vec<Vec<String>> {
+    let mut paragraphs = Vec::new();
+    let lines: Vec<&str> = text.lines().collect();
+    for paragraph in lines.split(|line| line.trim().is_empty()) {
+        let mut sentences = Vec::new();
+        let paragraph = paragraph.join(" ").split_whitespace().collect::<Vec<_>>().join(" ");
+        let mut current = String::new();
+        let mut chars = paragraph.chars().peekable();
+        while let Some(c) = chars.next() {
+            current.push(c);
+            if matches!(c, '.' | '!' | '?' | '…') {
+                // Comillas o paréntesis que cierran la frase
+                while let Some(&next) = chars.peek() {
+                    if matches!(next, '"' | '»' | ')' | '”' | '\'') {
+                        current.push(next);
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                if chars.peek().is_none_or(|c| c.is_whitespace()) {
+                    sentences.push(current.trim().to_string());
+                    current.clear();
+                }
+            }
+        }
+        if !current.trim().is_empty() {
+            sentences.push(current.trim().to_string());
+        }
+        if !sentences.is_empty() {
+            paragraphs.push(sentences);
+        }
+    }
+    paragraphs
+}
+
+/// Palabra sin la puntuación que la rodea
+fn core(token: &str) -> &str {
+    token.trim_matches(|c: char| !c.is_alphanumeric())
+}
+
+fn is_content_word(word: &str) -> bool {
+    word.chars().count() >= 5
+        && word.chars().all(char::is_alphabetic)
+        && !STOPWORDS.contains(&normalize(word).as_str())
+}
+
+fn starts_uppercase(word: &str) -> bool {
+    word.chars().next().is_some_and(char::is_uppercase)
+}
+
+/// Sustituye la palabra `index` de la frase conservando su puntuación
+fn replace_word(tokens: &[&str], index: usize, replacement: &str) -> String {
+    tokens
+        .iter()
+        .enumerate()
+        .map(|(i, token)| {
+            if i == index {
+                let word = core(token);
+                let start = token.find(word).unwrap_or(0);
+                format!("{}{}{}", &token[..start], replacement, &token[start + word.len()..])
+            } else {
+                token.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Índices de las palabras con contenido de una frase
+fn content_positions(tokens: &[&str]) -> Vec<usize> {
+    (0..tokens.len()).filter(|&i| is_content_word(core(tokens[i]))).collect()
+}
+
+/// Completar: oculta la palabra con contenido más larga de la frase
+fn cloze_question(sentence: &str) -> Option<Question> {
+    let tokens: Vec<&str> = sentence.split_whitespace().collect();
+    if !(6..=40).contains(&tokens.len()) || sentence.contains(CLOZE_GAP) {
+        return None;
+    }
+    let index = content_positions(&tokens)
+        .into_iter()
+        .max_by_key(|&i| core(tokens[i]).chars().count())?;
+
+    Some(Question {
+        question: replace_word(&tokens, index, CLOZE_GAP),
+        kind: QuestionKind::Cloze {
+            blanks: vec![ClozeBlank {
+                answer: core(tokens[index]).to_string(),
+                synonyms: Vec::new(),
+            }],
+        },
+    })
+}
+
+/// Reconocimiento: la frase real entre versiones con una palabra cambiada
+/// por otra del mismo documento
+fn recognition_question(sentence: &str, vocabulary: &[String], full_text: &str, rng: &mut impl Rng) -> Option<Question> {
+    let tokens: Vec<&str> = sentence.split_whitespace().collect();
+    if !(5..=30).contains(&tokens.len()) {
+        return None;
+    }
+    // Los nombres propios delatarían el cambio
+    let positions: Vec<usize> = content_positions(&tokens)
+        .into_iter()
+        .filter(|&i| !starts_uppercase(core(tokens[i])))
+        .collect();
+    if positions.is_empty() {
+        return None;
+    }
+
+    let mut options = vec![sentence.to_string()];
+    for _ in 0..20 {
+        if options.len() == 3 {
+            break;
+        }
+        let (Some(&index), Some(word)) = (positions.choose(rng), vocabulary.choose(rng)) else {
+            return None;
+        };
+        if tokens.iter().any(|t| normalize(core(t)) == normalize(word)) {
+            continue;
+        }
+        let lure = replace_word(&tokens, index, word);
+        if !options.contains(&lure) && !full_text.contains(&lure) {
+            options.push(lure);
+        }
+    }
+    if options.len() < 3 {
+        return None;
+    }
+
+    options.shuffle(rng);
+    let correct_answer = options.iter().position(|o| o == sentence)?;
+    Some(Question {
+        question: "¿Cuál de estas frases aparecía en el texto?".to_string(),
+        kind: QuestionKind::MultipleChoice { options, correct_answer },
+    })
+}
+
+/// Genera preguntas para una parte, alternando los dos tipos
+fn generate_questions(sentences: &[String], vocabulary: &[String], full_text: &str, rng: &mut impl Rng) -> Vec<Question> {
+    let words: usize = sentences.iter().map(|s| s.split_whitespace().count()).sum();
+    let wanted = (words / 80).clamp(2, 6);
+
+    let mut order: Vec<&String> = sentences.iter().collect();
+    order.shuffle(rng);
+
+    let mut questions = Vec::new();
+    for sentence in order {
+        if questions.len() >= wanted {
+            break;
+        }
+        let question = if questions.len() % 2 == 0 {
+            cloze_question(sentence).or_else(|| recognition_question(sentence, vocabulary, full_text, rng))
+        } else {
+            recognition_question(sentence, vocabulary, full_text, rng).or_else(|| cloze_question(sentence))
+        };
+        questions.extend(question);
+    }
+    questions
+}
+
+/// Agrupa frases consecutivas en partes de unas `passage_words` palabras.
+/// Cada parte conserva sus párrafos; uno que se corta sigue en la siguiente.
+fn split_parts(text: &str, passage_words: usize) -> Vec<Vec<Vec<String>>> {
+    let mut groups: Vec<Vec<Vec<String>>> = Vec::new();
+    let mut current: Vec<Vec<String>> = Vec::new();
+    // La parte en curso empezó a mitad de un párrafo
+    let mut continues = false;
+    let mut count = 0;
+    for paragraph in split_paragraphs(text) {
+        if current.is_empty() {
+            continues = false;
+        }
+        current.push(Vec::new());
+        for sentence in paragraph {
+            count += sentence.split_whitespace().count();
+            match current.last_mut() {
+                Some(last) => last.push(sentence),
+                None => current.push(vec![sentence]),
+            }
+            if count >= passage_words {
+                groups.push(std::mem::take(&mut current));
+                continues = true;
+                count = 0;
+            }
+        }
+    }
+    // Un resto corto se une a la parte anterior
+    if !current.is_empty() {
+        match groups.last_mut() {
+            Some(last) if count < passage_words / 2 => {
+                let mut rest = current.into_iter();
+                if continues {
+                    if let (Some(end), Some(start)) = (last.last_mut(), rest.next()) {
+                        end.extend(start);
+                    }
+                }
+                last.extend(rest);
+            }
+            _ => groups.push(current),
+        }
+    }
+    groups
+}
+
+/// Divide cada capítulo en partes de unas `passage_words` palabras, o deja
+/// cada capítulo entero si no se indica, y genera sus preguntas. Las partes
+/// se numeran en orden de lectura; las que no admiten ninguna pregunta se
+/// descartan.
+pub fn generate_passages(id_prefix: &str, chapters: &[Chapter], passage_words: Option<usize>) -> Vec<Passage> {
+    let full_text = chapters
+        .iter()
+        .flat_map(|c| c.text.split_whitespace())
+        .collect::<Vec<_>>()
+        .join(" ");
+    let mut vocabulary: Vec<String> = full_text
+        .split_whitespace()
+        .map(core)
+        .filter(|w| is_content_word(w) && !starts_uppercase(w))
+        .map(str::to_string)
+        .collect();
+    vocabulary.sort();
+    vocabulary.dedup();
+
+    let mut rng = rand::thread_rng();
+    let mut passages = Vec::new();
+    for chapter in chapters {
+        let groups = match passage_words {
+            Some(words) => split_parts(&chapter.text, words.max(50)),
+            None => vec![split_paragraphs(&chapter.text)],
+        };
+        let total = groups.len();
+        for (i, paragraphs) in groups.iter().enumerate() {
+            let sentences = paragraphs.concat();
+            let questions = generate_questions(&sentences, &vocabulary, &full_text, &mut rng);
+            if questions.is_empty() {
+                continue;
+            }
+            // Los párrafos se separan con una línea en blanco, como en el original
+            let text = paragraphs
+                .iter()
+                .map(|p| p.join(" "))
+                .collect::<Vec<_>>()
+                .join("\n\n");
+            passages.push(Passage {
+                id: format!("{}#{}", id_prefix, passages.len() + 1),
+                title: if total > 1 { format!("{} ({}/{})", chapter.title, i + 1, total) } else { chapter.title.clone() },
+                difficulty: Readability::analyze(&text).difficulty(),
+                text,
+                questions,
+            });
+        }
+    }
+    passages
+}
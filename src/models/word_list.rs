@@ -0,0 +1,311 @@
+//! Listas de palabras para Memoria de Palabras
+//!
+//! Además de la lista incluida, se cargan los archivos `.txt` del directorio
+//! `word_lists` dentro del directorio de datos del usuario. Cada línea tiene
+//! una palabra y, opcionalmente, etiquetas separadas por `;`:
+//! `palabra;categoría;frecuencia;idioma` (ver README).
+
+use rand::seq::SliceRandom;
+use std::fs;
+use std::path::Path;
+use super::Difficulty;
+use crate::utils::normalize::normalize;
+
+/// Nombre de la lista incluida en el juego
+pub const BUILTIN_LIST: &str = "Incluida";
+
+/// Palabra de una lista con sus etiquetas
+#[derive(Debug, Clone)]
+pub struct WordEntry {
+    pub word: String,
+    pub category: Option<String>,
+    /// Frecuencia de uso (cuanto más alta, más común y más fácil)
+    pub frequency: Option<f32>,
+    pub language: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct WordList {
+    pub name: String,
+    pub entries: Vec<WordEntry>,
+}
+
+/// Listas disponibles y problemas encontrados al cargarlas
+#[derive(Debug, Clone, Default)]
+pub struct WordListLibrary {
+    pub lists: Vec<WordList>,
+    pub errors: Vec<String>,
+}
+
+impl WordEntry {
+    /// Lee una línea `palabra;categoría;frecuencia;idioma`. Las etiquetas
+    /// vacías u omitidas se ignoran.
+    fn parse(line: &str) -> Result<Self, String> {
+        let mut fields = line.split(';').map(str::trim);
+        let word = fields.next().unwrap_or_default().to_string();
+        if word.is_empty() {
+            return Err(format!("falta la palabra en «{}»", line));
+        }
+        let tag = |field: Option<&str>| field.filter(|f| !f.is_empty()).map(str::to_string);
+        let category = tag(fields.next());
+        let frequency = match fields.next().filter(|f| !f.is_empty()) {
+            Some(f) => Some(
+                f.replace(',', ".")
+                    .parse::<f32>()
+                    .map_err(|_| format!("frecuencia «{}» no válida para «{}»", f, word))?,
+            ),
+            None => None,
+        };
+        let language = tag(fields.next());
+        Ok(Self { word, category, frequency, language })
+    }
+}
+
+impl WordList {
+    /// Lista incluida: palabras comunes, de uso medio y poco frecuentes
+    pub fn builtin() -> Self {
+        let tiers: [(f32, &[&str]); 3] = [
+            (100.0, &[
+                "casa", "perro", "sol", "mesa", "libro", "agua", "fuego", "árbol",
+                "flor", "cielo", "mar", "montaña", "río", "piedra", "luz", "noche",
+            ]),
+            (10.0, &[
+                "computadora", "teléfono", "automóvil", "biblioteca", "hospital", "universidad",
+                "restaurante", "supermercado", "farmacia", "aeropuerto", "estación", "oficina",
+            ]),
+            (1.0, &[
+                "epistemología", "neuroplasticidad", "fenomenología", "hermenéutica", "paradigma",
+                "metamorfosis", "episódico", "cronológico", "metodología", "taxonomía",
+            ]),
+        ];
+
+        let entries = tiers
+            .iter()
+            .flat_map(|(frequency, words)| {
+                words.iter().map(move |word| WordEntry {
+                    word: word.to_string(),
+                    category: None,
+                    frequency: Some(*frequency),
+                    language: Some("es".to_string()),
+                })
+            })
+            .collect();
+
+        Self { name: BUILTIN_LIST.to_string(), entries }
+    }
+
+    fn parse(name: &str, content: &str) -> Result<Self, String> {
+        let mut entries: Vec<WordEntry> = Vec::new();
+        for line in content.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let entry = WordEntry::parse(line)?;
+            // Una palabra repetida contaría dos veces al recordarla; «café» y
+            // «cafe» se corrigen igual, así que también cuentan como repetidas
+            if !entries.iter().any(|e| normalize(&e.word) == normalize(&entry.word)) {
+                entries.push(entry);
+            }
+        }
+        if entries.len() < 2 {
+            return Err("necesita al menos dos palabras".to_string());
+        }
+        Ok(Self { name: name.to_string(), entries })
+    }
+
+    /// Elige al azar `count` palabras de una dificultad. Las palabras se
+    /// ordenan de más a menos frecuentes y se reparten en tercios: las más
+    /// comunes son las fáciles. Sin frecuencia, una palabra se considera tan
+    /// común como corta es. Si el tercio no basta, se completa con las
+    /// palabras de dificultad más cercana.
+    pub fn pick_words(&self, difficulty: &Difficulty, count: usize) -> Vec<String> {
+        let mut sorted: Vec<&WordEntry> = self.entries.iter().collect();
+        sorted.sort_by(|a, b| {
+            let key = |e: &WordEntry| e.frequency.unwrap_or(-(e.word.chars().count() as f32));
+            key(b).total_cmp(&key(a))
+        });
+
+        let len = sorted.len();
+        let third = len.div_ceil(3);
+        let (mut start, mut end) = match difficulty {
+            Difficulty::Easy => (0, third),
+            Difficulty::Medium => (third.min(len), (2 * third).min(len)),
+            Difficulty::Hard => ((2 * third).min(len), len),
+        };
+        while end - start < count && (start > 0 || end < len) {
+            // Se amplía primero hacia el lado más fácil, salvo en las fáciles
+            if start > 0 && *difficulty != Difficulty::Easy {
+                start -= 1;
+            } else if end < len {
+                end += 1;
+            } else {
+                start -= 1;
+            }
+        }
+
+        let mut words: Vec<String> = sorted[start..end].iter().map(|e| e.word.clone()).collect();
+        words.shuffle(&mut rand::thread_rng());
+        words.truncate(count);
+        words
+    }
+
+    /// Resumen de las etiquetas, p. ej. "3 categorías · es, en"
+    pub fn describe(&self) -> String {
+        let mut categories: Vec<&str> = self.entries.iter().filter_map(|e| e.category.as_deref()).collect();
+        categories.sort();
+        categories.dedup();
+        let mut languages: Vec<&str> = self.entries.iter().filter_map(|e| e.language.as_deref()).collect();
+        languages.sort();
+        languages.dedup();
+        let with_frequency = self.entries.iter().filter(|e| e.frequency.is_some()).count();
+
+        let mut parts = vec![format!("{} palabras", self.entries.len())];
+        if !categories.is_empty() {
+            parts.push(format!("{} categorías", categories.len()));
+        }
+        if !languages.is_empty() {
+            parts.push(languages.join(", "));
+        }
+        if with_frequency < self.entries.len() {
+            parts.push("dificultad por longitud".to_string());
+        }
+        parts.join(" · ")
+    }
+}
+
+impl WordListLibrary {
+    /// Carga la lista incluida y los `.txt` de `dir`. Un directorio inexistente no es un error.
+    pub fn load(dir: &Path) -> Self {
+        let mut library = Self {
+            lists: vec![WordList::builtin()],
+            errors: Vec::new(),
+        };
+        let Ok(entries) = fs::read_dir(dir) else {
+            return library;
+        };
+
+        let mut paths: Vec<_> = entries
+            .filter_map(|e| e.ok().map(|e| e.path()))
+            .filter(|p| p.extension().is_some_and(|ext| ext == "txt"))
+            .collect();
+        paths.sort();
+
+        for path in paths {
+            let file = path.file_name().unwrap_or_default().to_string_lossy().to_string();
+            let name = path.file_stem().unwrap_or_default().to_string_lossy().to_string();
+            if library.get(&name).is_some() {
+                library.errors.push(format!("{}: ya hay una lista llamada «{}»", file, name));
+                continue;
+            }
+            let parsed = fs::read_to_string(&path)
+                .map_err(|e| e.to_string())
+                .and_then(|content| WordList::parse(&name, &content));
+            match parsed {
+                Ok(list) => library.lists.push(list),
+                Err(e) => library.errors.push(format!("{}: {}", file, e)),
+            }
+        }
+
+        library
+    }
+
+    pub fn get(&self, name: &str) -> Option<&WordList> {
+        self.lists.iter().find(|l| l.name == name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn list(words: &[(&str, f32)]) -> WordList {
+        WordList {
+            name: "prueba".to_string(),
+            entries: words
+                .iter()
+                .map(|(word, frequency)| WordEntry {
+                    word: word.to_string(),
+                    category: None,
+                    frequency: Some(*frequency),
+                    language: None,
+                })
+                .collect(),
+        }
+    }
+
+    fn sorted(mut words: Vec<String>) -> Vec<String> {
+        words.sort();
+        words
+    }
+
+    #[test]
+    fn parse_reads_all_tags() {
+        let entry = WordEntry::parse("gato; animal; 12,5 ;es").unwrap();
+        assert_eq!(entry.word, "gato");
+        assert_eq!(entry.category.as_deref(), Some("animal"));
+        assert_eq!(entry.frequency, Some(12.5));
+        assert_eq!(entry.language.as_deref(), Some("es"));
+    }
+
+    #[test]
+    fn parse_ignores_empty_tags() {
+        let entry = WordEntry::parse("gato;;;").unwrap();
+        assert_eq!(entry.category, None);
+        assert_eq!(entry.frequency, None);
+        assert_eq!(entry.language, None);
+        assert!(WordEntry::parse("gato").is_ok());
+    }
+
+    #[test]
+    fn parse_rejects_bad_frequency() {
+        assert!(WordEntry::parse("gato;animal;mucha").is_err());
+    }
+
+    #[test]
+    fn parse_rejects_missing_word() {
+        assert!(WordEntry::parse(";animal").is_err());
+        assert!(WordEntry::parse(" ;animal;3").is_err());
+    }
+
+    #[test]
+    fn list_drops_words_that_differ_only_in_accents() {
+        let list = WordList::parse("prueba", "café\ncafe\nCAFÉ\ntaza").unwrap();
+        let words: Vec<&str> = list.entries.iter().map(|e| e.word.as_str()).collect();
+        assert_eq!(words, ["café", "taza"]);
+    }
+
+    #[test]
+    fn pick_words_uses_frequency_terciles() {
+        let list = list(&[
+            ("a", 9.0), ("b", 8.0), ("c", 7.0),
+            ("d", 6.0), ("e", 5.0), ("f", 4.0),
+            ("g", 3.0), ("h", 2.0), ("i", 1.0),
+        ]);
+        assert_eq!(sorted(list.pick_words(&Difficulty::Easy, 3)), ["a", "b", "c"]);
+        assert_eq!(sorted(list.pick_words(&Difficulty::Medium, 3)), ["d", "e", "f"]);
+        assert_eq!(sorted(list.pick_words(&Difficulty::Hard, 3)), ["g", "h", "i"]);
+    }
+
+    #[test]
+    fn pick_words_widens_toward_easier_words() {
+        let list = list(&[
+            ("a", 9.0), ("b", 8.0), ("c", 7.0),
+            ("d", 6.0), ("e", 5.0), ("f", 4.0),
+            ("g", 3.0), ("h", 2.0), ("i", 1.0),
+        ]);
+        assert_eq!(sorted(list.pick_words(&Difficulty::Hard, 5)), ["e", "f", "g", "h", "i"]);
+        assert_eq!(sorted(list.pick_words(&Difficulty::Easy, 4)), ["a", "b", "c", "d"]);
+        assert_eq!(list.pick_words(&Difficulty::Medium, 20).len(), 9);
+    }
+
+    #[test]
+    fn pick_words_without_frequency_prefers_short_words() {
+        let mut list = list(&[("sol", 0.0), ("epistemología", 0.0), ("biblioteca", 0.0)]);
+        for entry in &mut list.entries {
+            entry.frequency = None;
+        }
+        assert_eq!(list.pick_words(&Difficulty::Easy, 1), ["sol"]);
+        assert_eq!(list.pick_words(&Difficulty::Hard, 1), ["epistemología"]);
+    }
+}
@@ -0,0 +1,197 @@
+//! Distancia de edición con transposiciones (Damerau-Levenshtein restringida)
+//!
+//! Además de la distancia, clasifica cada error según el tipo de operación
+//! necesaria para transformar la secuencia esperada en la respuesta.
+
+use serde::{Deserialize, Serialize};
+use std::ops::AddAssign;
+
+/// Recuento de errores por tipo
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EditErrors {
+    /// Elementos esperados que faltan en la respuesta
+    pub omissions: usize,
+    /// Elementos de la respuesta que no estaban en la secuencia
+    pub insertions: usize,
+    /// Elementos sustituidos por otro
+    pub substitutions: usize,
+    /// Pares adyacentes intercambiados
+    pub transpositions: usize,
+}
+
+impl EditErrors {
+    pub fn total(&self) -> usize {
+        self.omissions + self.insertions + self.substitutions + self.transpositions
+    }
+    
+    /// Resumen breve, p. ej. "1 omisión, 2 intercambios"
+    pub fn describe(&self) -> String {
+        let parts: Vec<String> = [
+            (self.omissions, "omisión", "omisiones"),
+            (self.insertions, "inserción", "inserciones"),
+            (self.substitutions, "sustitución", "sustituciones"),
+            (self.transpositions, "intercambio", "intercambios"),
+        ]
+        .iter()
+        .filter(|(count, _, _)| *count > 0)
+        .map(|(count, one, many)| format!("{} {}", count, if *count == 1 { one } else { many }))
+        .collect();
+        
+        if parts.is_empty() {
+            "sin errores".to_string()
+        } else {
+            parts.join(", ")
+        }
+    }
+}
+
+impl AddAssign for EditErrors {
+    fn add_assign(&mut self, other: Self) {
+        self.omissions += other.omissions;
+        self.insertions += other.insertions;
+        self.substitutions += other.substitutions;
+        self.transpositions += other.transpositions;
+    }
+}
+
+/// Alinea `actual` con `expected` y devuelve los errores de la alineación óptima
+pub fn align<T: PartialEq>(expected: &[T], actual: &[T]) -> EditErrors {
+    let n = expected.len();
+    let m = actual.len();
+    let mut d = vec![vec![0usize; m + 1]; n + 1];
+    
+    for (i, row) in d.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in d[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+    
+    for i in 1..=n {
+        for j in 1..=m {
+            let cost = if expected[i - 1] == actual[j - 1] { 0 } else { 1 };
+            let mut best = (d[i - 1][j] + 1)
+                .min(d[i][j - 1] + 1)
+                .min(d[i - 1][j - 1] + cost);
+            if is_transposition(expected, actual, i, j) {
+                best = best.min(d[i - 2][j - 2] + 1);
+            }
+            d[i][j] = best;
+        }
+    }
+    
+    // Reconstruir el camino para clasificar las operaciones
+    let mut errors = EditErrors::default();
+    let (mut i, mut j) = (n, m);
+    while i > 0 || j > 0 {
+        if i > 0 && j > 0 && expected[i - 1] == actual[j - 1] && d[i][j] == d[i - 1][j - 1] {
+            i -= 1;
+            j -= 1;
+        } else if is_transposition(expected, actual, i, j) && d[i][j] == d[i - 2][j - 2] + 1 {
+            errors.transpositions += 1;
+            i -= 2;
+            j -= 2;
+        } else if i > 0 && j > 0 && d[i][j] == d[i - 1][j - 1] + 1 {
+            errors.substitutions += 1;
+            i -= 1;
+            j -= 1;
+        } else if i > 0 && d[i][j] == d[i - 1][j] + 1 {
+            errors.omissions += 1;
+            i -= 1;
+        } else {
+            errors.insertions += 1;
+            j -= 1;
+        }
+    }
+    
+    errors
+}
+
+/// Número de letras que hay que cambiar para pasar de `a` a `b`
+pub fn distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    align(&a, &b).total()
+}
+
+fn is_transposition<T: PartialEq>(expected: &[T], actual: &[T], i: usize, j: usize) -> bool {
+    i > 1 && j > 1
+        && expected[i - 1] == actual[j - 2]
+        && expected[i - 2] == actual[j - 1]
+        && expected[i - 1] != expected[i - 2]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn errors(expected: &str, actual: &str) -> EditErrors {
+        let expected: Vec<char> = expected.chars().collect();
+        let actual: Vec<char> = actual.chars().collect();
+        align(&expected, &actual)
+    }
+
+    #[test]
+    fn identical_sequences_have_no_errors() {
+        assert_eq!(errors("1234", "1234"), EditErrors::default());
+    }
+
+    #[test]
+    fn missing_digit_is_an_omission() {
+        assert_eq!(errors("1234", "124"), EditErrors { omissions: 1, ..Default::default() });
+        assert_eq!(errors("1234", "234"), EditErrors { omissions: 1, ..Default::default() });
+    }
+
+    #[test]
+    fn extra_digit_is_an_insertion() {
+        assert_eq!(errors("123", "1293"), EditErrors { insertions: 1, ..Default::default() });
+        assert_eq!(errors("123", "1234"), EditErrors { insertions: 1, ..Default::default() });
+    }
+
+    #[test]
+    fn wrong_digit_is_a_substitution() {
+        assert_eq!(errors("123", "173"), EditErrors { substitutions: 1, ..Default::default() });
+    }
+
+    #[test]
+    fn adjacent_swap_is_one_transposition() {
+        assert_eq!(errors("12", "21"), EditErrors { transpositions: 1, ..Default::default() });
+        assert_eq!(errors("1234", "1324"), EditErrors { transpositions: 1, ..Default::default() });
+        assert_eq!(errors("1234", "2143"), EditErrors { transpositions: 2, ..Default::default() });
+    }
+
+    #[test]
+    fn repeated_digits_are_not_a_transposition() {
+        assert_eq!(errors("11", "11"), EditErrors::default());
+        assert_eq!(errors("112", "121").transpositions, 1);
+    }
+
+    #[test]
+    fn mixed_errors_are_each_counted() {
+        // Intercambio del 1 y el 2 y omisión del 4
+        assert_eq!(errors("12345", "2135"), EditErrors { omissions: 1, transpositions: 1, ..Default::default() });
+        // Sustitución del 2 e inserción del 9 al final
+        assert_eq!(errors("1234", "17349"), EditErrors { substitutions: 1, insertions: 1, ..Default::default() });
+    }
+
+    #[test]
+    fn empty_inputs() {
+        assert_eq!(errors("", ""), EditErrors::default());
+        assert_eq!(errors("", "12"), EditErrors { insertions: 2, ..Default::default() });
+        assert_eq!(errors("12", ""), EditErrors { omissions: 2, ..Default::default() });
+    }
+
+    #[test]
+    fn distance_counts_every_error() {
+        assert_eq!(distance("casa", "casa"), 0);
+        assert_eq!(distance("casa", "csaa"), 1);
+        assert_eq!(distance("casa", "cosas"), 2);
+    }
+
+    #[test]
+    fn describe_lists_error_kinds() {
+        assert_eq!(EditErrors::default().describe(), "sin errores");
+        let e = EditErrors { omissions: 1, transpositions: 2, ..Default::default() };
+        assert_eq!(e.describe(), "1 omisión, 2 intercambios");
+    }
+}
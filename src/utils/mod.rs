@@ -1 +1,4 @@
-pub mod time_format;
\ No newline at end of file
+pub mod time_format;
+pub mod edit_distance;
+pub mod normalize;
+pub mod readability;
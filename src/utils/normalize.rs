@@ -0,0 +1,87 @@
+//! Normalización de respuestas escritas
+//!
+//! Permite comparar lo que escribe el usuario sin tener en cuenta mayúsculas,
+//! tildes, diéresis ni la puntuación que rodea a las palabras. Las tildes se
+//! quitan tanto si vienen en la letra (á) como en un carácter combinante
+//! aparte (a + ◌́), como ocurre con algunos teclados y textos pegados.
+//!
+//! Cada letra se descompone (NFD) y se descartan sus marcas, así que vale
+//! para cualquier letra con diacríticos (ã, å, ý, ç...). La ñ es la excepción:
+//! es otra letra, y «año» no es «ano». Las letras que Unicode no descompone
+//! (ø, ł, đ...) se dejan como están.
+
+use unicode_normalization::char::{decompose_canonical, is_combining_mark};
+use unicode_normalization::UnicodeNormalization;
+
+/// Añade la letra sin tildes ni otras marcas (salvo la ñ, que se conserva)
+fn fold_char(c: char, out: &mut String) {
+    if c == 'ñ' {
+        out.push(c);
+        return;
+    }
+    decompose_canonical(c, |d| {
+        if !is_combining_mark(d) {
+            out.push(d);
+        }
+    });
+}
+
+/// Forma canónica para comparar: minúsculas, sin tildes, sin puntuación
+/// alrededor de las palabras y con los espacios reducidos a uno.
+pub fn normalize(text: &str) -> String {
+    text.split_whitespace()
+        .map(|word| {
+            // NFC primero para que n + ◌̃ se reconozca como ñ
+            let mut folded = String::new();
+            word.trim_matches(|c: char| !c.is_alphanumeric() && !is_combining_mark(c))
+                .nfc()
+                .flat_map(char::to_lowercase)
+                .for_each(|c| fold_char(c, &mut folded));
+            folded
+        })
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Compara dos textos ignorando mayúsculas, tildes y puntuación
+pub fn loosely_equal(a: &str, b: &str) -> bool {
+    normalize(a) == normalize(b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strips_case_accents_and_surrounding_punctuation() {
+        assert_eq!(normalize("  ¡Árbol,   CAFÉ!  "), "arbol cafe");
+        assert_eq!(normalize("pingüino"), "pinguino");
+        assert!(loosely_equal("¿Qué?", "que"));
+    }
+
+    #[test]
+    fn strips_marks_beyond_spanish() {
+        assert_eq!(normalize("São"), "sao");
+        assert_eq!(normalize("Ångström"), "angstrom");
+        assert_eq!(normalize("ýõçãèî"), "yocaei");
+    }
+
+    #[test]
+    fn strips_separate_combining_marks() {
+        assert_eq!(normalize("cafe\u{301}"), "cafe");
+        assert_eq!(normalize("\u{301}a"), "a");
+    }
+
+    #[test]
+    fn keeps_enye_distinct() {
+        assert_eq!(normalize("AÑO"), "año");
+        assert_eq!(normalize("an\u{303}o"), "año");
+        assert!(!loosely_equal("año", "ano"));
+    }
+
+    #[test]
+    fn leaves_undecomposable_letters() {
+        assert_eq!(normalize("Øresund"), "øresund");
+    }
+}
@@ -0,0 +1,197 @@
+//! Índices de legibilidad
+//!
+//! Para español se usan Fernández-Huerta y Szigriszt-Pazos (escala INFLESZ);
+//! para inglés, Flesch-Kincaid. El idioma se deduce de las palabras más
+//! frecuentes del texto.
+
+use crate::models::Difficulty;
+
+/// Idioma detectado del texto
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Language {
+    Spanish,
+    English,
+}
+
+/// Recuentos de un texto con los que se calculan los índices
+#[derive(Debug, Clone, Copy)]
+pub struct Readability {
+    pub language: Language,
+    pub words: usize,
+    pub sentences: usize,
+    pub syllables: usize,
+}
+
+const SPANISH_MARKERS: &[&str] = &[
+    "el", "la", "los", "las", "de", "del", "que", "y", "en", "un", "una", "es", "por", "con", "para", "se",
+];
+const ENGLISH_MARKERS: &[&str] = &[
+    "the", "of", "and", "to", "in", "is", "that", "it", "was", "for", "with", "on", "as", "are", "this", "be",
+];
+
+impl Readability {
+    pub fn analyze(text: &str) -> Self {
+        let words: Vec<String> = text
+            .split_whitespace()
+            .map(|w| w.trim_matches(|c: char| !c.is_alphanumeric()).to_lowercase())
+            .filter(|w| !w.is_empty())
+            .collect();
+
+        let spanish = words.iter().filter(|w| SPANISH_MARKERS.contains(&w.as_str())).count();
+        let english = words.iter().filter(|w| ENGLISH_MARKERS.contains(&w.as_str())).count();
+        let language = if english > spanish { Language::English } else { Language::Spanish };
+
+        let syllables = words
+            .iter()
+            .map(|w| match language {
+                Language::Spanish => spanish_syllables(w),
+                Language::English => english_syllables(w),
+            })
+            .sum();
+
+        Self {
+            language,
+            words: words.len(),
+            sentences: count_sentences(text),
+            syllables,
+        }
+    }
+
+    fn syllables_per_word(&self) -> f32 {
+        self.syllables as f32 / self.words.max(1) as f32
+    }
+
+    fn words_per_sentence(&self) -> f32 {
+        self.words as f32 / self.sentences.max(1) as f32
+    }
+
+    /// Fernández-Huerta: 0 (muy difícil) a 100 (muy fácil)
+    pub fn fernandez_huerta(&self) -> f32 {
+        let syllables_per_100 = self.syllables_per_word() * 100.0;
+        let sentences_per_100 = 100.0 / self.words_per_sentence().max(1.0);
+        206.84 - 0.60 * syllables_per_100 - 1.02 * sentences_per_100
+    }
+
+    /// Szigriszt-Pazos (perspicuidad): 0 (muy difícil) a 100 (muy fácil)
+    pub fn szigriszt_pazos(&self) -> f32 {
+        206.835 - 62.3 * self.syllables_per_word() - self.words_per_sentence()
+    }
+
+    /// Flesch-Kincaid: curso escolar estadounidense necesario para entenderlo
+    pub fn flesch_kincaid_grade(&self) -> f32 {
+        0.39 * self.words_per_sentence() + 11.8 * self.syllables_per_word() - 15.59
+    }
+
+    /// Dificultad que corresponde al texto
+    pub fn difficulty(&self) -> Difficulty {
+        match self.language {
+            Language::Spanish => match self.szigriszt_pazos() {
+                score if score >= 65.0 => Difficulty::Easy,
+                score if score >= 50.0 => Difficulty::Medium,
+                _ => Difficulty::Hard,
+            },
+            Language::English => match self.flesch_kincaid_grade() {
+                grade if grade <= 6.0 => Difficulty::Easy,
+                grade if grade <= 10.0 => Difficulty::Medium,
+                _ => Difficulty::Hard,
+            },
+        }
+    }
+
+    /// Resumen para mostrar, p. ej. "Szigriszt-Pazos 62 (normal) · Fernández-Huerta 70"
+    pub fn describe(&self) -> String {
+        match self.language {
+            Language::Spanish => {
+                let score = self.szigriszt_pazos();
+                let scale = match score {
+                    s if s >= 80.0 => "muy fácil",
+                    s if s >= 65.0 => "bastante fácil",
+                    s if s >= 55.0 => "normal",
+                    s if s >= 40.0 => "algo difícil",
+                    _ => "muy difícil",
+                };
+                format!(
+                    "Szigriszt-Pazos {:.0} ({}) · Fernández-Huerta {:.0}",
+                    score,
+                    scale,
+                    self.fernandez_huerta()
+                )
+            }
+            Language::English => format!("Flesch-Kincaid: curso {:.1} (inglés)", self.flesch_kincaid_grade()),
+        }
+    }
+}
+
+/// Frases: signos de cierre seguidos de espacio o fin de texto
+fn count_sentences(text: &str) -> usize {
+    let mut count = 0;
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if matches!(c, '.' | '!' | '?' | '…') {
+            while chars.peek().is_some_and(|c| matches!(c, '.' | '!' | '?' | '"' | '»' | ')')) {
+                chars.next();
+            }
+            if chars.peek().is_none_or(|c| c.is_whitespace()) {
+                count += 1;
+            }
+        }
+    }
+    // Un texto sin puntuación final cuenta como una frase
+    if count == 0 || !text.trim_end().ends_with(['.', '!', '?', '…', '"', '»', ')']) {
+        count += 1;
+    }
+    count
+}
+
+/// Sílabas de una palabra española (en minúsculas): cada grupo de vocales es
+/// una sílaba, salvo hiatos entre vocales fuertes o con vocal débil tildada.
+fn spanish_syllables(word: &str) -> usize {
+    let chars: Vec<char> = word.chars().collect();
+    let is_vowel = |c: char| "aeiouáéíóúü".contains(c);
+    let is_strong = |c: char| "aeoáéóíú".contains(c);
+
+    let mut count = 0;
+    let mut previous: Option<char> = None;
+    for (i, &c) in chars.iter().enumerate() {
+        // La u de "que", "qui", "gue", "gui" no se pronuncia
+        let silent_u = c == 'u'
+            && i > 0
+            && matches!(chars[i - 1], 'q' | 'g')
+            && chars.get(i + 1).is_some_and(|n| "eiéí".contains(*n));
+        // La y final tras vocal forma diptongo ("hoy", "rey")
+        let final_y = c == 'y' && i + 1 == chars.len();
+        let vowel = (is_vowel(c) && !silent_u) || (final_y && i == 0);
+
+        if vowel {
+            match previous {
+                Some(p) if !(is_strong(p) && is_strong(c)) => {}
+                _ => count += 1,
+            }
+            previous = Some(c);
+        } else if !silent_u && !final_y {
+            previous = None;
+        }
+    }
+    count.max(1)
+}
+
+/// Sílabas de una palabra inglesa (aproximación): grupos de vocales, sin la e
+/// muda final.
+fn english_syllables(word: &str) -> usize {
+    let chars: Vec<char> = word.chars().collect();
+    let is_vowel = |c: char| "aeiouy".contains(c);
+
+    let mut count = 0;
+    let mut in_vowel_group = false;
+    for &c in &chars {
+        let vowel = is_vowel(c);
+        if vowel && !in_vowel_group {
+            count += 1;
+        }
+        in_vowel_group = vowel;
+    }
+    if word.ends_with('e') && !word.ends_with("le") && count > 1 {
+        count -= 1;
+    }
+    count.max(1)
+}
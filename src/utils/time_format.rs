@@ -1,4 +1,4 @@
-use std::time::SystemTime;
+use std::time::{Duration, SystemTime};
 use chrono::{DateTime, Local};
 
 pub trait SystemTimeFormat {
@@ -10,4 +10,16 @@ impl SystemTimeFormat for SystemTime {
         let datetime: DateTime<Local> = (*self).into();
         datetime.format("%d/%m/%Y %H:%M").to_string()
     }
-}
\ No newline at end of file
+}
+
+/// Duración aproximada en la unidad más grande, p. ej. "8 min", "3 h", "2 días"
+pub fn format_duration_short(duration: Duration) -> String {
+    let minutes = duration.as_secs() / 60;
+    match minutes {
+        0 => "menos de 1 min".to_string(),
+        1..=59 => format!("{} min", minutes),
+        60..=1439 => format!("{} h", minutes / 60),
+        1440..=2879 => "1 día".to_string(),
+        _ => format!("{} días", minutes / 1440),
+    }
+}
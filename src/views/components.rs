@@ -71,3 +71,140 @@ pub fn stats_group(ui: &mut egui::Ui, title: &str, stats: &[(&str, String)]) {
         }
     });
 }
+
+/// Color entre verde (0.0) y rojo (1.0) según una tasa de error
+pub fn error_rate_color(rate: f32) -> egui::Color32 {
+    let rate = rate.clamp(0.0, 1.0);
+    egui::Color32::from_rgb(
+        (40.0 + 160.0 * rate) as u8,
+        (160.0 - 110.0 * rate) as u8,
+        (60.0 - 10.0 * rate) as u8,
+    )
+}
+
+/// Dibuja los casilleros de iNumbs en verde/rojo, con el valor esperado
+/// debajo de los incorrectos
+pub fn box_result_grid(ui: &mut egui::Ui, boxes: &[crate::models::BoxResult]) {
+    let cols = 6usize;
+    for row in boxes.chunks(cols) {
+        ui.horizontal(|ui| {
+            for b in row {
+                let fill = if b.correct { error_rate_color(0.0) } else { error_rate_color(1.0) };
+                egui::Frame::none()
+                    .fill(fill)
+                    .rounding(4.0)
+                    .inner_margin(egui::Margin::same(6.0))
+                    .show(ui, |ui| {
+                        ui.set_min_width(48.0);
+                        ui.vertical_centered(|ui| {
+                            let answer = if b.answer.is_empty() { "—" } else { b.answer.as_str() };
+                            ui.label(egui::RichText::new(answer).color(egui::Color32::WHITE).strong());
+                            if !b.correct {
+                                ui.label(egui::RichText::new(&b.expected).color(egui::Color32::WHITE).small());
+                            }
+                        });
+                    });
+            }
+        });
+    }
+}
+
+/// Fila de celdas coloreadas según la tasa de error de cada tramo
+pub fn heatmap_row(ui: &mut egui::Ui, rates: &[Option<f32>]) {
+    ui.horizontal(|ui| {
+        for rate in rates {
+            ui.vertical(|ui| {
+                let (rect, _) = ui.allocate_exact_size(egui::vec2(40.0, 28.0), egui::Sense::hover());
+                let color = match rate {
+                    Some(rate) => error_rate_color(*rate),
+                    None => egui::Color32::GRAY,
+                };
+                ui.painter().rect_filled(rect, 3.0, color);
+                ui.label(match rate {
+                    Some(rate) => format!("{:.0}%", rate * 100.0),
+                    None => "—".to_string(),
+                });
+            });
+        }
+    });
+}
+
+/// Gráfico de líneas simple. Cada serie es (nombre, color, valores) y todas
+/// comparten el eje vertical, que empieza en cero.
+pub fn line_chart(ui: &mut egui::Ui, height: f32, series: &[(&str, egui::Color32, Vec<f32>)]) {
+    let max_value = series
+        .iter()
+        .flat_map(|(_, _, values)| values.iter().copied())
+        .fold(0.0f32, f32::max);
+    let max_len = series.iter().map(|(_, _, values)| values.len()).max().unwrap_or(0);
+    
+    let width = ui.available_width().min(600.0);
+    let (rect, _) = ui.allocate_exact_size(egui::vec2(width, height), egui::Sense::hover());
+    let painter = ui.painter_at(rect);
+    let stroke_color = ui.visuals().weak_text_color();
+    painter.rect_stroke(rect, 2.0, egui::Stroke::new(1.0, stroke_color));
+    
+    if max_len == 0 || max_value <= 0.0 {
+        return;
+    }
+    
+    let plot = rect.shrink(8.0);
+    let point = |i: usize, value: f32| {
+        let x = if max_len > 1 {
+            plot.left() + plot.width() * i as f32 / (max_len - 1) as f32
+        } else {
+            plot.center().x
+        };
+        egui::pos2(x, plot.bottom() - plot.height() * value / max_value)
+    };
+    
+    for (_, color, values) in series {
+        let points: Vec<egui::Pos2> = values.iter().enumerate().map(|(i, v)| point(i, *v)).collect();
+        painter.add(egui::Shape::line(points.clone(), egui::Stroke::new(2.0, *color)));
+        for p in points {
+            painter.circle_filled(p, 3.0, *color);
+        }
+    }
+    
+    painter.text(
+        rect.left_top() + egui::vec2(4.0, 2.0),
+        egui::Align2::LEFT_TOP,
+        format!("{:.0}", max_value),
+        egui::FontId::proportional(11.0),
+        stroke_color,
+    );
+    
+    ui.horizontal(|ui| {
+        for (name, color, _) in series {
+            ui.colored_label(*color, format!("● {}", name));
+        }
+    });
+}
+
+/// Curva de posición serial de Memoria de Palabras: acierto en cada posición
+/// de la lista, con el resumen de las primeras (primacía) y las últimas (recencia).
+pub fn serial_position_chart(ui: &mut egui::Ui, words: &[String], scores: &[f32]) {
+    let percent: Vec<f32> = scores.iter().map(|s| s * 100.0).collect();
+    ui.label("Acierto (%) según la posición en la lista:");
+    line_chart(ui, 120.0, &[("Acierto", egui::Color32::from_rgb(50, 120, 200), percent)]);
+    
+    let edge = (scores.len() / 4).max(1);
+    let mean = |values: &[f32]| values.iter().sum::<f32>() / values.len().max(1) as f32 * 100.0;
+    ui.label(format!(
+        "Primacía (primeras {}): {:.0}% · Recencia (últimas {}): {:.0}%",
+        edge,
+        mean(&scores[..edge.min(scores.len())]),
+        edge,
+        mean(&scores[scores.len().saturating_sub(edge)..])
+    ));
+    
+    let missed: Vec<&str> = words
+        .iter()
+        .zip(scores)
+        .filter(|(_, &score)| score <= 0.0)
+        .map(|(word, _)| word.as_str())
+        .collect();
+    if !missed.is_empty() {
+        ui.label(format!("Olvidadas: {}", missed.join(", ")));
+    }
+}
@@ -1,9 +1,10 @@
 //! Vista del historial
 
 use eframe::egui;
-use crate::models::{GameType, GameDetails, AppState};
+use crate::models::{GameType, GameDetails, AppState, Grade, NumberRound, RecallOrder, RetentionPoint};
 use crate::controllers::AppController;
-use crate::utils::time_format::SystemTimeFormat;
+use crate::utils::time_format::{format_duration_short, SystemTimeFormat};
+use super::components::{box_result_grid, heatmap_row, line_chart, serial_position_chart};
 
 /// Renderiza la vista de historial de partidas
 pub fn render_history(ui: &mut egui::Ui, controller: &mut AppController) {
@@ -27,6 +28,9 @@ pub fn render_history(ui: &mut egui::Ui, controller: &mut AppController) {
     
     // Mostrar estadísticas generales
     render_general_stats(ui, &results, controller);
+    render_inumbs_heatmap(ui, controller);
+    render_reading_rate_chart(ui, &results);
+    render_forgetting_curves(ui, controller);
     
     ui.add_space(20.0);
     
@@ -43,8 +47,13 @@ pub fn render_history(ui: &mut egui::Ui, controller: &mut AppController) {
 
 fn render_general_stats(ui: &mut egui::Ui, results: &[crate::models::GameResult], controller: &AppController) {
     ui.collapsing("📊 Resumen General", |ui| {
-        let total_games = results.len();
-        let total_score: f32 = results.iter().map(|r| r.score).sum();
+        // Las pruebas diferidas no son partidas nuevas
+        let games: Vec<_> = results
+            .iter()
+            .filter(|r| !matches!(r.details, GameDetails::DelayedRecall { .. }))
+            .collect();
+        let total_games = games.len();
+        let total_score: f32 = games.iter().map(|r| r.score).sum();
         let avg_score = if total_games > 0 { total_score / total_games as f32 } else { 0.0 };
         
         ui.label(format!("Total de partidas: {}", total_games));
@@ -54,12 +63,116 @@ fn render_general_stats(ui: &mut egui::Ui, results: &[crate::models::GameResult]
         for game_type in GameType::all() {
             let stats = controller.get_stats_for_game(&game_type);
             
-            if stats.total_games > 0 {
+            if !stats.variants.is_empty() {
                 ui.separator();
                 ui.strong(game_type.name());
                 ui.label(format!("  Partidas: {}", stats.total_games));
                 ui.label(format!("  Mejor puntuación: {:.1}", stats.best_score));
+                
+                if stats.has_variants() {
+                    for variant in &stats.variants {
+                        ui.label(format!(
+                            "  · {}: {} partidas, mejor {:.1}, media {:.1}",
+                            variant.name,
+                            variant.total_games,
+                            variant.best_score,
+                            variant.average_score()
+                        ));
+                    }
+                }
+            }
+        }
+    });
+}
+
+/// Mapa de calor de errores por posición en iNumbs, agregando todas las partidas
+fn render_inumbs_heatmap(ui: &mut egui::Ui, controller: &AppController) {
+    let thirds = controller.get_inumbs_position_errors(3);
+    if thirds.iter().all(|p| p.total == 0) {
+        return;
+    }
+    
+    ui.collapsing("🔥 iNumbs: errores por posición", |ui| {
+        ui.label("Tasa de error según la posición del número en la secuencia:");
+        ui.add_space(6.0);
+        let rates: Vec<Option<f32>> = controller
+            .get_inumbs_position_errors(10)
+            .iter()
+            .map(|p| (p.total > 0).then(|| p.error_rate()))
+            .collect();
+        heatmap_row(ui, &rates);
+        ui.label("(de izquierda a derecha: del primer número al último)");
+        
+        ui.add_space(6.0);
+        for (name, stats) in ["Inicio", "Medio", "Final"].iter().zip(&thirds) {
+            ui.label(format!(
+                "{}: {:.0}% de errores ({} de {})",
+                name,
+                stats.error_rate() * 100.0,
+                stats.errors,
+                stats.total
+            ));
+        }
+    });
+}
+
+/// Evolución de la velocidad de lectura en Comprensión de Texto
+fn render_reading_rate_chart(ui: &mut egui::Ui, results: &[crate::models::GameResult]) {
+    let mut sessions: Vec<(std::time::SystemTime, f32, f32)> = results
+        .iter()
+        .filter_map(|r| match r.details {
+            GameDetails::TextComprehension { wpm, effective_wpm, .. } if wpm > 0.0 => {
+                Some((r.timestamp, wpm, effective_wpm))
             }
+            _ => None,
+        })
+        .collect();
+    if sessions.is_empty() {
+        return;
+    }
+    sessions.sort_by_key(|(timestamp, _, _)| *timestamp);
+    
+    let wpm: Vec<f32> = sessions.iter().map(|(_, wpm, _)| *wpm).collect();
+    let effective: Vec<f32> = sessions.iter().map(|(_, _, effective)| *effective).collect();
+    
+    ui.collapsing("📈 Velocidad de lectura", |ui| {
+        ui.label("Palabras por minuto en cada partida de Comprensión de Texto, de la más antigua a la más reciente:");
+        line_chart(ui, 160.0, &[
+            ("Velocidad (ppm)", egui::Color32::from_rgb(50, 120, 200), wpm),
+            ("Velocidad efectiva", egui::Color32::from_rgb(40, 160, 60), effective),
+        ]);
+    });
+}
+
+/// Curva del olvido de los juegos con pruebas de recuerdo diferido
+fn render_forgetting_curves(ui: &mut egui::Ui, controller: &AppController) {
+    let curves: Vec<(GameType, Vec<RetentionPoint>)> = [GameType::WordMemory, GameType::INumbs]
+        .into_iter()
+        .map(|game_type| {
+            let curve = controller.get_forgetting_curve(&game_type);
+            (game_type, curve)
+        })
+        .filter(|(_, curve)| !curve.is_empty())
+        .collect();
+    if curves.is_empty() {
+        return;
+    }
+    
+    ui.collapsing("📉 Curva del olvido", |ui| {
+        ui.label("Retención: acierto en la prueba diferida respecto al acierto al terminar la partida.");
+        for (game_type, curve) in &curves {
+            ui.add_space(6.0);
+            ui.strong(game_type.name());
+            let mut retention = vec![100.0];
+            retention.extend(curve.iter().map(|p| p.retention * 100.0));
+            line_chart(ui, 100.0, &[("Retención (%)", egui::Color32::from_rgb(200, 120, 40), retention)]);
+            
+            let mut points = vec!["Inmediato: 100%".to_string()];
+            points.extend(curve.iter().map(|p| {
+                let tests = if p.tests == 1 { "prueba" } else { "pruebas" };
+                format!("{}: {:.0}% ({} {})", p.delay.name(), p.retention * 100.0, p.tests, tests)
+            }));
+            ui.label(points.join(" · "));
         }
     });
 }
@@ -69,7 +182,7 @@ fn render_results_list(ui: &mut egui::Ui, results: &[crate::models::GameResult])
         .max_height(400.0)
         .show(ui, |ui| {
             let mut sorted_results = results.to_vec();
-            sorted_results.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+            sorted_results.sort_by_key(|r| std::cmp::Reverse(r.timestamp));
             
             for (i, result) in sorted_results.iter().enumerate() {
                 if i > 0 {
@@ -80,28 +193,134 @@ fn render_results_list(ui: &mut egui::Ui, results: &[crate::models::GameResult])
                     ui.horizontal(|ui| {
                         ui.vertical(|ui| {
                             ui.strong(result.game_type.name());
+                            if let Some(variant) = result.details.variant() {
+                                ui.label(format!("Modo: {}", variant));
+                            }
                             ui.label(format!("📅 {}", result.timestamp.format_dm_yhm()));
                             ui.label(format!("🏆 Puntuación: {:.1}", result.score));
                         });
                         
                         ui.vertical(|ui| {
                             match &result.details {
-                                GameDetails::ReadingSpeed { words_correct, total_words, .. } => {
+                                GameDetails::ReadingSpeed { words_correct, total_words, span_estimate, .. } => {
                                     ui.label(format!("Correctas: {}/{}", words_correct, total_words));
+                                    if let Some(span) = span_estimate {
+                                        ui.label(format!("Amplitud: {:.1} dígitos", span));
+                                    }
                                 }
-                                GameDetails::WordMemory { words_correct, original_words, .. } => {
+                                GameDetails::WordMemory { words_correct, original_words, list_name, .. } => {
                                     ui.label(format!("Memorizado: {}/{}", words_correct, original_words.len()));
+                                    if !list_name.is_empty() {
+                                        ui.label(format!("Lista: {}", list_name));
+                                    }
                                 }
-                                GameDetails::TextComprehension { questions_correct, total_questions, .. } => {
+                                GameDetails::TextComprehension { questions_correct, total_questions, wpm, effective_wpm, .. } => {
                                     ui.label(format!("Respuestas: {}/{}", questions_correct, total_questions));
+                                    if *wpm > 0.0 {
+                                        ui.label(format!("{:.0} ppm (efectiva {:.0})", wpm, effective_wpm));
+                                    }
                                 }
                                 GameDetails::INumbs { correct, total, .. } => {
                                     ui.label(format!("Números: {}/{}", correct, total));
                                 }
+                                GameDetails::PairedAssociates { pairs, .. } => {
+                                    let correct = pairs.iter().filter(|p| p.correct).count();
+                                    ui.label(format!("Parejas: {}/{}", correct, pairs.len()));
+                                }
+                                GameDetails::DelayedRecall { item_scores, elapsed, .. } => {
+                                    let recalled = item_scores.iter().filter(|&&s| s > 0.0).count();
+                                    ui.label(format!(
+                                        "Recordado: {}/{} tras {}",
+                                        recalled,
+                                        item_scores.len(),
+                                        format_duration_short(*elapsed)
+                                    ));
+                                }
+                                GameDetails::Flashcards { reviews } => {
+                                    let remembered = reviews.iter().filter(|r| r.grade != Grade::Again).count();
+                                    ui.label(format!("Tarjetas: {}/{} recordadas", remembered, reviews.len()));
+                                }
+                                GameDetails::MajorSystem { responses } => {
+                                    let correct = responses.iter().filter(|r| r.correct).count();
+                                    ui.label(format!("Imágenes: {}/{}", correct, responses.len()));
+                                }
+                                GameDetails::SchulteTable { time_taken, errors, .. } => {
+                                    ui.label(format!("Tiempo: {:.1} s, errores: {}", time_taken.as_secs_f32(), errors));
+                                }
+                                GameDetails::Tachistoscope { trials, max_width, .. } => {
+                                    let correct = trials.iter().filter(|t| t.correct).count();
+                                    ui.label(format!("Aciertos: {}/{}, anchura: {}", correct, trials.len(), max_width));
+                                }
                             }
                         });
                     });
+                    
+                    if let GameDetails::ReadingSpeed { rounds, order, .. } = &result.details {
+                        if !rounds.is_empty() {
+                            egui::CollapsingHeader::new("🔍 Ver rondas")
+                                .id_source(("rondas", i))
+                                .show(ui, |ui| render_round_breakdown(ui, i, rounds, *order));
+                        }
+                    }
+                    
+                    if let GameDetails::WordMemory { original_words, position_scores, .. } = &result.details {
+                        if !position_scores.is_empty() {
+                            egui::CollapsingHeader::new("📈 Curva de posición serial")
+                                .id_source(("posicion", i))
+                                .show(ui, |ui| serial_position_chart(ui, original_words, position_scores));
+                        }
+                    }
+                    
+                    if let GameDetails::INumbs { boxes, .. } = &result.details {
+                        if !boxes.is_empty() {
+                            egui::CollapsingHeader::new("🔍 Ver casilleros")
+                                .id_source(("casilleros", i))
+                                .show(ui, |ui| box_result_grid(ui, boxes));
+                        }
+                    }
                 });
             }
         });
 }
+
+/// Tabla con el detalle de cada ronda de Memoria Numérica
+fn render_round_breakdown(ui: &mut egui::Ui, index: usize, rounds: &[NumberRound], order: RecallOrder) {
+    egui::Grid::new(("tabla_rondas", index))
+        .striped(true)
+        .spacing([12.0, 4.0])
+        .show(ui, |ui| {
+            ui.strong("#");
+            ui.strong("Número");
+            ui.strong("Respuesta");
+            ui.strong("Dígitos");
+            ui.strong("Visualización");
+            ui.strong("Respuesta en");
+            ui.strong("Precisión");
+            ui.strong("Errores");
+            ui.end_row();
+            
+            for (n, round) in rounds.iter().enumerate() {
+                ui.label(format!("{}", n + 1));
+                match order {
+                    RecallOrder::Forward => ui.monospace(&round.number),
+                    RecallOrder::Backward => ui.monospace(format!(
+                        "{} → {}",
+                        round.number,
+                        round.number.chars().rev().collect::<String>()
+                    )),
+                };
+                let answer = egui::RichText::new(&round.user_answer).monospace();
+                if round.correct {
+                    ui.label(answer.color(egui::Color32::from_rgb(40, 160, 60)));
+                } else {
+                    ui.label(answer.color(egui::Color32::from_rgb(200, 50, 50)));
+                }
+                ui.label(format!("{}", round.digit_count));
+                ui.label(format!("{} ms", round.display_time.as_millis()));
+                ui.label(format!("{:.1} s", round.response_time.as_secs_f32()));
+                ui.label(format!("{:.0}%", round.accuracy));
+                ui.label(round.errors.describe());
+                ui.end_row();
+            }
+        });
+}
@@ -1,7 +1,9 @@
 //! Vista del menú principal
 
 use eframe::egui;
-use crate::models::{GameType, AppState};
+use std::time::SystemTime;
+use crate::models::{GameType, AppState, PendingRecall};
+use crate::utils::time_format::format_duration_short;
 use crate::controllers::AppController;
 
 /// Renderiza la vista de selección de juegos
@@ -19,6 +21,8 @@ pub fn render_menu(ui: &mut egui::Ui, controller: &mut AppController) {
         }
     });
     
+    render_pending_recalls(ui, controller);
+    
     ui.add_space(20.0);
     ui.separator();
     ui.add_space(20.0);
@@ -29,6 +33,42 @@ pub fn render_menu(ui: &mut egui::Ui, controller: &mut AppController) {
     }
 }
 
+/// Pruebas de recuerdo diferido: las que ya tocan, con su botón, y cuándo es la siguiente
+fn render_pending_recalls(ui: &mut egui::Ui, controller: &mut AppController) {
+    let pending = controller.get_pending_recalls();
+    if pending.is_empty() {
+        return;
+    }
+    let due: Vec<&PendingRecall> = pending.iter().filter(|p| p.is_due()).collect();
+    
+    ui.add_space(10.0);
+    ui.group(|ui| {
+        ui.strong(format!("⏰ Pruebas de recuerdo pendientes: {}", due.len()));
+        for recall in &due {
+            ui.horizontal(|ui| {
+                let studied = recall.studied_at.elapsed().unwrap_or_default();
+                ui.label(format!(
+                    "{} · {} elementos · estudiado hace {}",
+                    recall.game_type.name(),
+                    recall.stimulus.len(),
+                    format_duration_short(studied)
+                ));
+                if ui.button("Empezar").clicked() {
+                    controller.start_recall_test(recall.studied_at);
+                }
+            });
+        }
+        if let Some(next) = pending.iter().find(|p| !p.is_due()) {
+            let wait = next.due_at().duration_since(SystemTime::now()).unwrap_or_default();
+            ui.label(format!(
+                "Siguiente: {} dentro de {}",
+                next.game_type.name(),
+                format_duration_short(wait)
+            ));
+        }
+    });
+}
+
 /// Renderiza una tarjeta de juego
 fn render_game_card(ui: &mut egui::Ui, controller: &mut AppController, game_type: &GameType) {
     let stats = controller.get_stats_for_game(game_type);
@@ -42,9 +82,23 @@ fn render_game_card(ui: &mut egui::Ui, controller: &mut AppController, game_type
                 ui.heading(game_type.name());
                 ui.label(game_type.description());
                 
-                if stats.total_games > 0 {
+                if *game_type == GameType::Flashcards {
+                    let due = controller.get_due_card_count();
+                    if due > 0 {
+                        ui.strong(format!("📅 Tarjetas para hoy: {}", due));
+                    } else {
+                        ui.label("Sin tarjetas pendientes hoy");
+                    }
+                }
+                
+                if !stats.variants.is_empty() {
                     ui.label(format!("Partidas jugadas: {}", stats.total_games));
                     ui.label(format!("Mejor puntuación: {:.1}", stats.best_score));
+                    if stats.has_variants() {
+                        for variant in &stats.variants {
+                            ui.label(format!("  {}: mejor {:.1}", variant.name, variant.best_score));
+                        }
+                    }
                 } else {
                     ui.label("Sin partidas jugadas");
                 }
@@ -1,9 +1,10 @@
 //! Vista de resultados
 
 use eframe::egui;
-use crate::models::{AppState, GameDetails};
+use crate::models::{AppState, FlashcardReview, GameDetails, Grade};
+use super::components::{box_result_grid, serial_position_chart};
 use crate::controllers::AppController;
-use crate::utils::time_format::SystemTimeFormat;
+use crate::utils::time_format::{format_duration_short, SystemTimeFormat};
 
 /// Renderiza la vista de resultados después de un juego
 pub fn render_results(ui: &mut egui::Ui, controller: &mut AppController) {
@@ -26,17 +27,172 @@ pub fn render_results(ui: &mut egui::Ui, controller: &mut AppController) {
         ui.add_space(20.0);
         
         match &result.details {
-            GameDetails::ReadingSpeed { words_correct, total_words, .. } => {
+            GameDetails::ReadingSpeed { words_correct, total_words, errors, progression, span_estimate, order, chunking, .. } => {
                 ui.label(format!("Respuestas correctas: {} de {}", words_correct, total_words));
+                ui.label(format!("Orden de recuerdo: {}", order.name()));
+                ui.label(format!("Agrupación: {}", chunking.name()));
+                ui.label(format!("Progresión: {}", progression.name()));
+                if let Some(span) = span_estimate {
+                    ui.label(format!("Amplitud de dígitos estimada: {:.1}", span));
+                }
+                ui.add_space(10.0);
+                ui.group(|ui| {
+                    ui.label("Tipos de error:");
+                    ui.label(format!("  Omisiones (dígitos olvidados): {}", errors.omissions));
+                    ui.label(format!("  Inserciones (dígitos de más): {}", errors.insertions));
+                    ui.label(format!("  Sustituciones (dígito equivocado): {}", errors.substitutions));
+                    ui.label(format!("  Intercambios (pares invertidos): {}", errors.transpositions));
+                });
             }
-            GameDetails::WordMemory { words_correct, original_words, .. } => {
+            GameDetails::WordMemory { words_correct, original_words, recall_mode, position_scores, list_name, near_misses, intrusions, .. } => {
                 ui.label(format!("Palabras recordadas: {} de {}", words_correct, original_words.len()));
+                ui.label(format!("  Exactas: {}", words_correct.saturating_sub(near_misses.len())));
+                if !near_misses.is_empty() {
+                    let typos: Vec<String> = near_misses.iter().map(|m| format!("{} → {}", m.answer, m.target)).collect();
+                    ui.label(format!("  Con errata: {} ({})", near_misses.len(), typos.join(", ")));
+                }
+                if !intrusions.is_empty() {
+                    ui.label(format!("Intrusiones (no estaban en la lista): {}", intrusions.join(", ")));
+                }
+                ui.label(format!("Recuerdo: {}", recall_mode.name()));
+                if !list_name.is_empty() {
+                    ui.label(format!("Lista: {}", list_name));
+                }
+                if !position_scores.is_empty() {
+                    ui.add_space(10.0);
+                    serial_position_chart(ui, original_words, position_scores);
+                }
             }
-            GameDetails::TextComprehension { questions_correct, total_questions, .. } => {
+            GameDetails::TextComprehension { questions_correct, total_questions, reading_time, wpm, effective_wpm, reading_mode, guide_wpm, .. } => {
                 ui.label(format!("Respuestas correctas: {} de {}", questions_correct, total_questions));
+                ui.label(format!("Modo de lectura: {}", reading_mode.name()));
+                if let Some(guide) = guide_wpm {
+                    ui.label(format!("Velocidad de la guía al terminar: {:.0} ppm", guide));
+                }
+                ui.label(format!("Tiempo de lectura: {:.1} s", reading_time.as_secs_f32()));
+                ui.label(format!("Velocidad: {:.0} palabras por minuto", wpm));
+                ui.label(format!("Velocidad efectiva: {:.0} ppm (velocidad × comprensión)", effective_wpm));
             }
-            GameDetails::INumbs { correct, total, .. } => {
+            GameDetails::INumbs { correct, total, presentation, boxes, number_format, .. } => {
                 ui.label(format!("Números correctos: {} de {}", correct, total));
+                ui.label(format!("Formato: {}", number_format.name()));
+                ui.label(format!("Presentación: {}", presentation.name()));
+                if !boxes.is_empty() {
+                    ui.add_space(10.0);
+                    egui::ScrollArea::vertical().max_height(300.0).show(ui, |ui| {
+                        box_result_grid(ui, boxes);
+                    });
+                }
+            }
+            GameDetails::PairedAssociates { pairs, list_name } => {
+                let correct = pairs.iter().filter(|p| p.correct).count();
+                ui.label(format!("Parejas recordadas: {} de {}", correct, pairs.len()));
+                if !list_name.is_empty() {
+                    ui.label(format!("Lista: {}", list_name));
+                }
+                if !pairs.is_empty() {
+                    let total: std::time::Duration = pairs.iter().map(|p| p.latency).sum();
+                    let average = total / pairs.len() as u32;
+                    ui.label(format!("Tiempo medio de respuesta: {:.1} s", average.as_secs_f32()));
+                    
+                    ui.add_space(10.0);
+                    for pair in pairs {
+                        let text = format!(
+                            "{} — {}: {} ({:.1} s)",
+                            pair.cue,
+                            pair.target,
+                            if pair.answer.is_empty() { "—" } else { &pair.answer },
+                            pair.latency.as_secs_f32()
+                        );
+                        if pair.correct {
+                            ui.colored_label(egui::Color32::from_rgb(40, 160, 60), format!("✔ {}", text));
+                        } else {
+                            ui.colored_label(egui::Color32::from_rgb(200, 50, 50), format!("✘ {}", text));
+                        }
+                    }
+                }
+            }
+            GameDetails::DelayedRecall { delay, elapsed, immediate_score, stimulus, answers, item_scores, .. } => {
+                let recalled = item_scores.iter().filter(|&&s| s > 0.0).count();
+                ui.label(format!("Recuerdo diferido ({}): {} de {}", delay.name(), recalled, stimulus.len()));
+                ui.label(format!("Tiempo desde el estudio: {}", format_duration_short(*elapsed)));
+                let delayed = item_scores.iter().sum::<f32>() / item_scores.len().max(1) as f32;
+                ui.label(format!(
+                    "Acierto: {:.0}% ahora, {:.0}% al terminar la partida",
+                    delayed * 100.0,
+                    immediate_score * 100.0
+                ));
+                
+                ui.add_space(10.0);
+                for (i, (item, score)) in stimulus.iter().zip(item_scores).enumerate() {
+                    let answer = answers.get(i).map(String::as_str).filter(|a| !a.is_empty()).unwrap_or("—");
+                    if *score > 0.0 {
+                        ui.colored_label(egui::Color32::from_rgb(40, 160, 60), format!("✔ {}", item));
+                    } else {
+                        ui.colored_label(egui::Color32::from_rgb(200, 50, 50), format!("✘ {} ({})", item, answer));
+                    }
+                }
+            }
+            GameDetails::Flashcards { reviews } => {
+                let new = reviews.iter().filter(|r| r.was_new).count();
+                ui.label(format!("Repasos: {} ({} tarjetas nuevas)", reviews.len(), new));
+                for grade in Grade::all() {
+                    let count = reviews.iter().filter(|r| r.grade == grade).count();
+                    ui.label(format!("  {}: {}", grade.name(), count));
+                }
+                let mut intervals: Vec<&FlashcardReview> = reviews.iter().filter(|r| r.grade != Grade::Again).collect();
+                intervals.sort_by_key(|r| std::cmp::Reverse(r.interval_days));
+                if let Some(longest) = intervals.first() {
+                    ui.label(format!("Próximo repaso más lejano: «{}» dentro de {} días", longest.front, longest.interval_days));
+                }
+            }
+            GameDetails::MajorSystem { responses } => {
+                let correct = responses.iter().filter(|r| r.correct).count();
+                ui.label(format!("Respuestas correctas: {} de {}", correct, responses.len()));
+                if !responses.is_empty() {
+                    let total: std::time::Duration = responses.iter().map(|r| r.response_time).sum();
+                    let average = total / responses.len() as u32;
+                    ui.label(format!("Tiempo medio de respuesta: {:.1} s", average.as_secs_f32()));
+                    
+                    let mut slowest = responses.clone();
+                    slowest.sort_by_key(|r| std::cmp::Reverse(r.response_time));
+                    let slowest: Vec<String> = slowest
+                        .iter()
+                        .take(5)
+                        .map(|r| format!("{:02} ({:.1} s)", r.number, r.response_time.as_secs_f32()))
+                        .collect();
+                    ui.label(format!("Más lentos: {}", slowest.join(", ")));
+                }
+            }
+            GameDetails::SchulteTable { grid_size, symbols, time_taken, errors, click_latencies } => {
+                ui.label(format!("Tabla: {}×{} ({})", grid_size, grid_size, symbols.name()));
+                ui.label(format!("Tiempo: {:.1} s", time_taken.as_secs_f32()));
+                ui.label(format!("Errores: {}", errors));
+                if let Some((slowest, latency)) = click_latencies.iter().enumerate().max_by_key(|(_, l)| **l) {
+                    ui.label(format!(
+                        "Búsqueda más lenta: {} ({:.1} s)",
+                        symbols.symbol(slowest),
+                        latency.as_secs_f32()
+                    ));
+                }
+            }
+            GameDetails::Tachistoscope { stimulus, trials, max_width } => {
+                let correct = trials.iter().filter(|t| t.correct).count();
+                ui.label(format!("Estímulo: {}", stimulus.name()));
+                ui.label(format!("Aciertos: {} de {}", correct, trials.len()));
+                ui.label(format!("Anchura máxima leída: {}", max_width));
+                if let Some(fastest) = trials.iter().filter(|t| t.correct).map(|t| t.shown_for).min() {
+                    ui.label(format!("Exposición más breve acertada: {} ms", fastest.as_millis()));
+                }
+                if !trials.is_empty() {
+                    // Diferencia media entre el tiempo pedido y el realmente mostrado
+                    let deviation: f32 = trials
+                        .iter()
+                        .map(|t| (t.shown_for.as_secs_f32() - t.exposure.as_secs_f32()).abs())
+                        .sum::<f32>()
+                        / trials.len() as f32;
+                    ui.label(format!("Desviación media de la exposición: {:.1} ms", deviation * 1000.0));
+                }
             }
         }
         